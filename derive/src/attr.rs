@@ -0,0 +1,99 @@
+use syn::{punctuated::Punctuated, Attribute, Error, LitStr, Path, Result, Token, WherePredicate};
+
+/// Container attributes given as `#[pure_clone(...)]`.
+#[derive(Default)]
+pub struct Attrs {
+    /// `#[pure_clone(copy)]`: Also implement `Copy` and clone with `*self`.
+    pub copy: bool,
+    /// `#[pure_clone(crate = "path")]`: Overrides the path to the `clone_cell` crate.
+    pub krate: Option<Path>,
+    /// `#[pure_clone(trusted)]`: Also implement `TrustedPureClone`.
+    pub trusted: bool,
+    /// `#[pure_clone(unsafe_trust_clone)]`: Use the existing `Clone` impl instead of generating one.
+    pub unsafe_trust_clone: bool,
+    /// `#[pure_clone(where = "predicates")]`: Extra predicates added to the generated impls.
+    pub where_predicates: Vec<WherePredicate>,
+}
+
+impl Attrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut out = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("pure_clone")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("copy") {
+                    out.copy = true;
+                    Ok(())
+                } else if meta.path.is_ident("trusted") {
+                    out.trusted = true;
+                    Ok(())
+                } else if meta.path.is_ident("unsafe_trust_clone") {
+                    out.unsafe_trust_clone = true;
+                    Ok(())
+                } else if meta.path.is_ident("where") {
+                    let s: LitStr = meta.value()?.parse()?;
+                    let predicates =
+                        s.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+                    out.where_predicates.extend(predicates);
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    let s: LitStr = meta.value()?.parse()?;
+                    out.krate = Some(s.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `pure_clone` attribute"))
+                }
+            })?;
+            if out.copy && out.unsafe_trust_clone {
+                return Err(Error::new_spanned(
+                    attr,
+                    "`copy` and `unsafe_trust_clone` cannot be used together",
+                ));
+            }
+            if out.trusted && out.unsafe_trust_clone {
+                return Err(Error::new_spanned(
+                    attr,
+                    "`trusted` and `unsafe_trust_clone` cannot be used together, because a \
+                     hand-written `clone` may panic",
+                ));
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Returns whether the `#[repr(...)]` attributes include `packed` or `packed(N)`.
+pub fn is_packed(attrs: &[Attribute]) -> Result<bool> {
+    let mut packed = false;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("packed") {
+                packed = true;
+            }
+            // Skip over any arguments such as in `packed(2)` or `align(8)`.
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(packed)
+}
+
+/// Returns the path of `Clone` if it is derived in one of `attrs`.
+///
+/// Only `#[derive]` attributes that come after the one invoking this macro are visible here, so
+/// this does not catch `#[derive(Clone, PureClone)]`.
+pub fn derived_clone(attrs: &[Attribute]) -> Result<Option<Path>> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident("derive")) {
+        let paths = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+        if let Some(path) = paths
+            .into_iter()
+            .find(|p| p.segments.last().is_some_and(|s| s.ident == "Clone"))
+        {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
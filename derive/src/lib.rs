@@ -1,50 +1,23 @@
-use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
-use syn::spanned::Spanned;
-use synstructure::{decl_derive, AddBounds, Structure};
+use syn::{parse_macro_input, DeriveInput, Error};
 
-decl_derive!([PureClone] => derive_pure_clone);
+mod attr;
+mod pure_clone;
+mod pure_clone_impl;
 
-fn derive_pure_clone(mut s: Structure) -> TokenStream {
-    s.underscore_const(true);
-    s.add_bounds(AddBounds::Fields);
-    let body = s.each_variant(|v| {
-        let bindings = v.bindings();
-        v.construct(|_, i| {
-            let b = &bindings[i];
-            // TODO: Proper span
-            quote! { core::clone::Clone::clone(#b) }
-        })
-    });
-    // Asserts are used instead of adding additional `where` clauses on the `PureClone` impl
-    // below. This is because `where` clauses that contain the `Self` type can lead to overflowing
-    // evaluating trait requirements in the recursive cases.
-    let asserts = s.variants().iter().flat_map(|v| {
-        v.ast().fields.iter().map(|f| {
-            let ty = &f.ty;
-            let span = ty.span();
-            quote_spanned! {span=>
-                let _ = <#ty as clone_cell::clone::PureClone>::pure_clone;
-            }
-        })
-    });
-    let output = s.gen_impl(quote! {
-        gen impl core::clone::Clone for @Self {
-            fn clone(&self) -> Self {
-                match *self {
-                    #body
-                }
-            }
-        }
-
-        gen unsafe impl clone_cell::clone::PureClone for @Self {
-            #[inline]
-            fn pure_clone(&self) -> Self {
-                #(#asserts)*
+#[proc_macro_derive(PureClone, attributes(pure_clone))]
+pub fn derive_pure_clone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    pure_clone::expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
 
-                core::clone::Clone::clone(self)
-            }
-        }
-    });
-    output
+#[proc_macro_attribute]
+pub fn pure_clone_impl(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    pure_clone_impl::expand(args.into(), input.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
 }
@@ -0,0 +1,388 @@
+use proc_macro2::{Group, Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Error, Field, GenericArgument, Index, PathArguments,
+    Result, Type, TypeParamBound,
+};
+use synstructure::{AddBounds, BindStyle, Structure};
+
+use crate::attr::{derived_clone, is_packed, Attrs};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    if let Data::Union(data) = &input.data {
+        return Err(Error::new_spanned(
+            data.union_token,
+            "`PureClone` cannot be derived for unions",
+        ));
+    }
+    let mut s = Structure::try_new(input)?;
+    let attrs = Attrs::parse(&s.ast().attrs)?;
+    let packed = is_packed(&s.ast().attrs)?;
+    if !attrs.unsafe_trust_clone {
+        if let Some(path) = derived_clone(&s.ast().attrs)? {
+            return Err(Error::new_spanned(
+                path,
+                "`Clone` cannot be derived along with `PureClone`, which already implements it",
+            ));
+        }
+    }
+    let krate = match &attrs.krate {
+        Some(path) => quote! { #path },
+        None => quote! { ::clone_cell },
+    };
+    s.underscore_const(true);
+    // Bounds are added to each impl below instead.
+    s.add_bounds(AddBounds::None);
+    for predicate in &attrs.where_predicates {
+        s.add_where_predicate(predicate.clone());
+    }
+    let body = if attrs.copy {
+        quote! { *self }
+    } else if packed {
+        // Fields of a packed struct may be unaligned, so they cannot be borrowed. Instead, each
+        // field is copied out first and the copy is cloned, which requires the field to be `Copy`.
+        // `repr(packed)` is only allowed on `struct`s, so there is exactly one variant.
+        let v = &s.variants()[0];
+        let asserts = v.ast().fields.iter().map(|f| {
+            let ty = &f.ty;
+            quote_spanned! {ty.span()=>
+                {
+                    fn assert_packed_field_is_copy<T: ::core::marker::Copy>() {}
+                    assert_packed_field_is_copy::<#ty>();
+                }
+            }
+        });
+        let construct = v.construct(|f, i| {
+            let member = member(f, i);
+            quote! { ::core::clone::Clone::clone(&{ self.#member }) }
+        });
+        quote! {
+            #(#asserts)*
+
+            #construct
+        }
+    } else if let Data::Struct(_) = &input.data {
+        // Fields of a struct are accessed directly, which is less code than a `match`.
+        let construct = s.variants()[0].construct(|f, i| {
+            let member = member(f, i);
+            let clone = quote_spanned! {f.span()=> ::core::clone::Clone::clone };
+            let field = quote_spanned! {f.span()=> &self.#member };
+            quote! { #clone(#field) }
+        });
+        quote! { #construct }
+    } else {
+        let arms = s.each_variant(|v| {
+            let bindings = v.bindings();
+            v.construct(|f, i| {
+                let b = &bindings[i];
+                let clone = quote_spanned! {f.span()=> ::core::clone::Clone::clone };
+                quote! { #clone(#b) }
+            })
+        });
+        quote! {
+            match *self {
+                #arms
+            }
+        }
+    };
+    // `clone_from` reuses the resources of `self` by calling `clone_from` on each field when both
+    // values are the same variant. This is not done for `copy`, where `*self = *source` is
+    // already optimal, or for packed structs, whose fields cannot be borrowed.
+    let clone_from = if attrs.copy || packed || s.variants().is_empty() {
+        quote! {}
+    } else if let Data::Struct(data) = &input.data {
+        let fields = data.fields.iter().enumerate().map(|(i, f)| {
+            let member = member(f, i);
+            quote_spanned! {f.span()=>
+                ::core::clone::Clone::clone_from(&mut self.#member, &source.#member);
+            }
+        });
+        quote! {
+            #[inline]
+            fn clone_from(&mut self, source: &Self) {
+                #(#fields)*
+            }
+        }
+    } else {
+        let mut dst = s.clone();
+        dst.bind_with(|_| BindStyle::RefMut)
+            .binding_name(|f, i| format_ident!("__self_{}", i, span = f.span()));
+        let mut src = s.clone();
+        src.binding_name(|f, i| format_ident!("__source_{}", i, span = f.span()));
+        let arms = dst.variants().iter().zip(src.variants()).map(|(d, s)| {
+            let (d_pat, s_pat) = (d.pat(), s.pat());
+            let fields = d.bindings().iter().zip(s.bindings()).map(|(d, s)| {
+                quote_spanned! {d.ast().span()=>
+                    ::core::clone::Clone::clone_from(#d, #s);
+                }
+            });
+            quote! { (#d_pat, #s_pat) => { #(#fields)* } }
+        });
+        let fallback = if s.variants().len() > 1 {
+            quote! { _ => *self = ::core::clone::Clone::clone(source), }
+        } else {
+            quote! {}
+        };
+        quote! {
+            #[inline]
+            fn clone_from(&mut self, source: &Self) {
+                match (&mut *self, source) {
+                    #(#arms)*
+                    #fallback
+                }
+            }
+        }
+    };
+    // Bounds cannot be added for fields whose types mention the type being defined, e.g.,
+    // `Option<Box<Self>>`. Proving such a bound requires proving that `Self` implements the trait,
+    // which in turn requires the bound again, and so on until evaluating the trait requirements
+    // overflows. Leaving these bounds out means no bound can lead back to the impl it is on, so
+    // directly recursive types never overflow. (Types that are generic and mutually recursive
+    // through one another's fields still can.) The field types left out are instead checked
+    // below, using the remaining bounds.
+    let name = &s.ast().ident;
+    let (impl_generics, ty_generics, where_clause) = s.ast().generics.split_for_impl();
+    let self_ty = quote! { #name #ty_generics };
+    let tys = generic_field_types(&s)
+        .into_iter()
+        .filter(|ty| !mentions_self(ty.to_token_stream(), name))
+        .collect::<Vec<_>>();
+    let bounds = |bound: TokenStream| {
+        let predicates = tys.iter().map(|ty| {
+            quote_spanned! {ty.span()=>
+                #ty: #bound,
+            }
+        });
+        quote! { #(#predicates)* }
+    };
+    let clone_bounds = bounds(quote! { ::core::clone::Clone });
+    let pure_clone_bounds = bounds(quote! { #krate::clone::PureClone });
+    // Each field type that is not bounded above, other than a function pointer, is asserted to be
+    // `PureClone` in a function with the same generics and bounds as the `PureClone` impl. This
+    // fails to compile if the field type is not `PureClone`, independent of whether the impl is
+    // ever used. `Self` is not available in a free function, so it is replaced with the type being
+    // defined. The function takes that type as an argument so that it gets the same implied bounds
+    // as the impl, e.g., `T: 'a` for a field of type `&'a T`.
+    // Each type is only asserted once, however many fields have it.
+    let mut asserted: Vec<&Type> = Vec::new();
+    for f in s.variants().iter().flat_map(|v| v.ast().fields) {
+        if !tys.contains(&&f.ty) && !is_fn_pointer(&f.ty) && !asserted.contains(&&f.ty) {
+            asserted.push(&f.ty);
+        }
+    }
+    let asserts = asserted.iter().map(|&ty| {
+        let span = ty.span();
+        match known_field_assertion(ty) {
+            Some((assert, inner)) => {
+                let assert = Ident::new(assert, span);
+                let inner = replace_self(inner.to_token_stream(), &self_ty);
+                quote_spanned! {span=>
+                    #krate::__private::#assert::<#inner>();
+                }
+            }
+            None => {
+                let ty = replace_self(ty.to_token_stream(), &self_ty);
+                quote_spanned! {span=>
+                    let _ = <#ty as #krate::clone::PureClone>::pure_clone;
+                }
+            }
+        }
+    });
+    let assert_fn = |name: &str, bounds: &TokenStream, asserts: Vec<TokenStream>| {
+        if asserts.is_empty() {
+            return quote! {};
+        }
+        let name = Ident::new(name, Span::call_site());
+        let predicates = where_clause.map(|w| &w.predicates).into_iter().flatten();
+        let where_predicates = &attrs.where_predicates;
+        quote! {
+            #[allow(dead_code)]
+            fn #name #impl_generics (_: &#self_ty)
+            where
+                #(#predicates,)*
+                #bounds
+                #(#where_predicates,)*
+            {
+                #(#asserts)*
+            }
+        }
+    };
+    let pure_clone_assert_fn = assert_fn(
+        "assert_fields_are_pure_clone",
+        &pure_clone_bounds,
+        asserts.collect(),
+    );
+    // With `copy` or `repr(packed)`, `clone` needs every generic field to be `Copy` as well.
+    // Non-generic fields are checked by the `Copy` impl or the asserts above. A trusted `Clone`
+    // impl is not generated, so it does not need these bounds.
+    let copy_bounds = if attrs.copy || (packed && !attrs.unsafe_trust_clone) {
+        bounds(quote! { ::core::marker::Copy })
+    } else {
+        quote! {}
+    };
+    let copy_impl = if attrs.copy {
+        quote! {
+            gen impl ::core::marker::Copy for @Self where #copy_bounds {}
+        }
+    } else {
+        quote! {}
+    };
+    // `TrustedPureClone` is checked the same way as `PureClone`, except that there are no tailored
+    // assertions. The generated `clone` only clones the fields, so it cannot panic if they cannot.
+    let trusted_impl = if attrs.trusted {
+        let trusted_bounds = bounds(quote! { #krate::clone::TrustedPureClone });
+        let asserts = asserted.iter().map(|&ty| {
+            let span = ty.span();
+            let ty = replace_self(ty.to_token_stream(), &self_ty);
+            quote_spanned! {span=>
+                #krate::__private::assert_trusted_pure_clone::<#ty>();
+            }
+        });
+        let assert_fn = assert_fn(
+            "assert_fields_are_trusted_pure_clone",
+            &trusted_bounds,
+            asserts.collect(),
+        );
+        quote! {
+            gen unsafe impl #krate::clone::TrustedPureClone for @Self
+            where
+                #trusted_bounds
+                #copy_bounds
+            {
+            }
+
+            #assert_fn
+        }
+    } else {
+        quote! {}
+    };
+    // With `unsafe_trust_clone`, the user provides the `Clone` impl and vouches for it.
+    let clone_impl = if attrs.unsafe_trust_clone {
+        quote! {}
+    } else {
+        quote! {
+            gen impl ::core::clone::Clone for @Self where #clone_bounds #copy_bounds {
+                fn clone(&self) -> Self {
+                    #body
+                }
+
+                #clone_from
+            }
+        }
+    };
+    Ok(s.gen_impl(quote! {
+        #clone_impl
+
+        gen unsafe impl #krate::clone::PureClone for @Self where #pure_clone_bounds #copy_bounds {}
+
+        #pure_clone_assert_fn
+
+        #trusted_impl
+
+        #copy_impl
+    }))
+}
+
+/// Returns the name or index of the `i`th field `f`, to access it as `self.#member`.
+fn member(f: &Field, i: usize) -> TokenStream {
+    match &f.ident {
+        Some(ident) => quote! { #ident },
+        None => {
+            let i = Index {
+                index: i as u32,
+                span: f.ty.span(),
+            };
+            quote! { #i }
+        }
+    }
+}
+
+/// Returns the deduplicated types of all fields that reference a generic type parameter.
+///
+/// Function pointers are left out. Their `Clone` impl is built into the compiler and copies the
+/// pointer, so they need neither bounds nor assertions. This matters for higher-ranked function
+/// pointers such as `for<'a> fn(&'a T)`, which the `PureClone` impls cannot cover.
+fn generic_field_types<'a>(s: &Structure<'a>) -> Vec<&'a Type> {
+    let mut tys: Vec<&Type> = Vec::new();
+    for b in s.variants().iter().flat_map(|v| v.bindings()) {
+        let ty = &b.ast().ty;
+        if !b.referenced_ty_params().is_empty() && !is_fn_pointer(ty) && !tys.contains(&ty) {
+            tys.push(ty);
+        }
+    }
+    tys
+}
+
+/// Returns whether `ty` is a function pointer type.
+fn is_fn_pointer(ty: &Type) -> bool {
+    match ty {
+        Type::BareFn(_) => true,
+        Type::Group(group) => is_fn_pointer(&group.elem),
+        Type::Paren(paren) => is_fn_pointer(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Returns whether `tokens` mention `Self` or the type named `name`.
+fn mentions_self(tokens: TokenStream, name: &Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => ident == "Self" || ident == *name,
+        TokenTree::Group(group) => mentions_self(group.stream(), name),
+        _ => false,
+    })
+}
+
+/// Replaces each `Self` in `tokens` with `self_ty`.
+fn replace_self(tokens: TokenStream, self_ty: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|tt| match tt {
+            TokenTree::Ident(ident) if ident == "Self" => self_ty.clone(),
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self(group.stream(), self_ty));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced).into()
+            }
+            tt => tt.into(),
+        })
+        .collect()
+}
+
+/// Returns the name of an assertion helper with a tailored error message if `ty` looks like a type
+/// that is well known not to be `PureClone`, along with the type to pass to the helper.
+///
+/// This is purely syntactic, so it may match user types that happen to share a name. That is fine,
+/// because the helpers still accept any type that is `PureClone`.
+fn known_field_assertion(ty: &Type) -> Option<(&'static str, &Type)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let last = path.path.segments.last()?;
+    match &*last.ident.to_string() {
+        "RefCell" => Some(("assert_ref_cell_field", ty)),
+        "Mutex" | "RwLock" => Some(("assert_lock_field", ty)),
+        "Cell" => Some(("assert_cell_field", ty)),
+        "Box" => {
+            let PathArguments::AngleBracketed(args) = &last.arguments else {
+                return None;
+            };
+            let Some(GenericArgument::Type(inner @ Type::TraitObject(object))) = args.args.first()
+            else {
+                return None;
+            };
+            let is_closure =
+                object.bounds.iter().any(|bound| match bound {
+                    TypeParamBound::Trait(bound) => bound.path.segments.last().is_some_and(|s| {
+                        s.ident == "Fn" || s.ident == "FnMut" || s.ident == "FnOnce"
+                    }),
+                    _ => false,
+                });
+            is_closure.then_some(("assert_boxed_closure_field", inner))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,54 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{meta, parse::Parser, Error, ItemImpl, LitStr, Result};
+
+pub fn expand(args: TokenStream, input: TokenStream) -> Result<TokenStream> {
+    let mut acknowledged = false;
+    let mut krate = quote! { ::clone_cell };
+    meta::parser(|meta| {
+        if meta.path.is_ident("unsafe") {
+            acknowledged = true;
+            Ok(())
+        } else if meta.path.is_ident("crate") {
+            let s: LitStr = meta.value()?.parse()?;
+            let path: syn::Path = s.parse()?;
+            krate = quote! { #path };
+            Ok(())
+        } else {
+            Err(meta.error("unsupported `pure_clone_impl` attribute"))
+        }
+    })
+    .parse2(args)?;
+    let item: ItemImpl = syn::parse2(input)?;
+    let is_clone = match &item.trait_ {
+        Some((None, path, _)) => path.segments.last().is_some_and(|s| s.ident == "Clone"),
+        _ => false,
+    };
+    if !is_clone {
+        return Err(Error::new_spanned(
+            &item.self_ty,
+            "`pure_clone_impl` can only be used on an `impl Clone for ...` block",
+        ));
+    }
+    if !acknowledged {
+        // The `Clone` impl is kept, to avoid follow-up errors about it missing.
+        let error = Error::new_spanned(
+            &item.self_ty,
+            "`pure_clone_impl` does not check the `Clone` impl; write \
+             `#[pure_clone_impl(unsafe)]` to acknowledge that it must be pure",
+        )
+        .into_compile_error();
+        return Ok(quote! {
+            #item
+
+            #error
+        });
+    }
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    let self_ty = &item.self_ty;
+    Ok(quote! {
+        #item
+
+        unsafe impl #impl_generics #krate::clone::PureClone for #self_ty #where_clause {}
+    })
+}
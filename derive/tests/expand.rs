@@ -0,0 +1,76 @@
+//! Compares the output of the derive macro with the expected expansions in `tests/expand`.
+//!
+//! Each `tests/expand/<name>.rs` file contains items to derive `PureClone` for, and the formatted
+//! output is compared with `tests/expand/<name>.expanded.rs`. Run with `EXPAND=overwrite` to update
+//! the expected expansions. This requires `rustfmt`.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::{env, fs};
+
+use quote::ToTokens;
+use syn::{DeriveInput, Error};
+
+#[path = "../src/attr.rs"]
+mod attr;
+#[path = "../src/pure_clone.rs"]
+mod pure_clone;
+
+fn expand(input: &str) -> String {
+    let file = syn::parse_file(input).unwrap();
+    let mut output = String::new();
+    for item in file.items {
+        let input: DeriveInput = syn::parse2(item.into_token_stream()).unwrap();
+        let tokens = pure_clone::expand(&input).unwrap_or_else(Error::into_compile_error);
+        output += &tokens.to_string();
+        output += "\n";
+    }
+    format(&output)
+}
+
+fn format(source: &str) -> String {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to run `rustfmt`");
+    rustfmt
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+    let output = rustfmt.wait_with_output().unwrap();
+    assert!(output.status.success(), "failed to format:\n{source}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn expand_snapshots() {
+    let overwrite = env::var_os("EXPAND").is_some_and(|v| v == "overwrite");
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/expand");
+    let mut inputs = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| !path.to_string_lossy().ends_with(".expanded.rs"))
+        .collect::<Vec<_>>();
+    inputs.sort();
+    assert!(!inputs.is_empty());
+    for input in inputs {
+        let actual = expand(&fs::read_to_string(&input).unwrap());
+        let expected_path = input.with_extension("expanded.rs");
+        if overwrite {
+            fs::write(&expected_path, &actual).unwrap();
+            continue;
+        }
+        let expected = fs::read_to_string(&expected_path).unwrap_or_default();
+        assert!(
+            actual == expected,
+            "unexpected expansion of {}:\n{actual}",
+            input.display(),
+        );
+    }
+}
@@ -0,0 +1,13 @@
+const _: () = {
+    impl ::core::clone::Clone for Point {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    unsafe impl ::clone_cell::clone::PureClone for Point {}
+    #[allow(dead_code)]
+    fn assert_fields_are_pure_clone(_: &Point) {
+        let _ = <i32 as ::clone_cell::clone::PureClone>::pure_clone;
+    }
+    impl ::core::marker::Copy for Point {}
+};
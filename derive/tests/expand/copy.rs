@@ -0,0 +1,2 @@
+#[pure_clone(copy)]
+struct Point(i32, i32);
@@ -0,0 +1,54 @@
+const _: () = {
+    impl<T> ::core::clone::Clone for Msg<T>
+    where
+        T: ::core::clone::Clone,
+    {
+        fn clone(&self) -> Self {
+            match *self {
+                Msg::Ping => Msg::Ping,
+                Msg::Data(ref __binding_0, ref __binding_1) => Msg::Data(
+                    ::core::clone::Clone::clone(__binding_0),
+                    ::core::clone::Clone::clone(__binding_1),
+                ),
+                Msg::Meta {
+                    tag: ref __binding_0,
+                } => Msg::Meta {
+                    tag: ::core::clone::Clone::clone(__binding_0),
+                },
+            }
+        }
+        #[inline]
+        fn clone_from(&mut self, source: &Self) {
+            match (&mut *self, source) {
+                (Msg::Ping, Msg::Ping) => {}
+                (
+                    Msg::Data(ref mut __self_0, ref mut __self_1),
+                    Msg::Data(ref __source_0, ref __source_1),
+                ) => {
+                    ::core::clone::Clone::clone_from(__self_0, __source_0);
+                    ::core::clone::Clone::clone_from(__self_1, __source_1);
+                }
+                (
+                    Msg::Meta {
+                        tag: ref mut __self_0,
+                    },
+                    Msg::Meta {
+                        tag: ref __source_0,
+                    },
+                ) => {
+                    ::core::clone::Clone::clone_from(__self_0, __source_0);
+                }
+                _ => *self = ::core::clone::Clone::clone(source),
+            }
+        }
+    }
+    unsafe impl<T> ::clone_cell::clone::PureClone for Msg<T> where T: ::clone_cell::clone::PureClone {}
+    #[allow(dead_code)]
+    fn assert_fields_are_pure_clone<T>(_: &Msg<T>)
+    where
+        T: ::clone_cell::clone::PureClone,
+    {
+        let _ = <u32 as ::clone_cell::clone::PureClone>::pure_clone;
+        let _ = <Rc<str> as ::clone_cell::clone::PureClone>::pure_clone;
+    }
+};
@@ -0,0 +1,5 @@
+enum Msg<T> {
+    Ping,
+    Data(T, u32),
+    Meta { tag: Rc<str> },
+}
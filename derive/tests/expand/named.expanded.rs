@@ -0,0 +1,40 @@
+const _: () = {
+    impl<T> ::core::clone::Clone for Config<T>
+    where
+        T: ::core::clone::Clone,
+        Rc<T>: ::core::clone::Clone,
+    {
+        fn clone(&self) -> Self {
+            Config {
+                name: ::core::clone::Clone::clone(&self.name),
+                retries: ::core::clone::Clone::clone(&self.retries),
+                timeout: ::core::clone::Clone::clone(&self.timeout),
+                value: ::core::clone::Clone::clone(&self.value),
+                shared: ::core::clone::Clone::clone(&self.shared),
+            }
+        }
+        #[inline]
+        fn clone_from(&mut self, source: &Self) {
+            ::core::clone::Clone::clone_from(&mut self.name, &source.name);
+            ::core::clone::Clone::clone_from(&mut self.retries, &source.retries);
+            ::core::clone::Clone::clone_from(&mut self.timeout, &source.timeout);
+            ::core::clone::Clone::clone_from(&mut self.value, &source.value);
+            ::core::clone::Clone::clone_from(&mut self.shared, &source.shared);
+        }
+    }
+    unsafe impl<T> ::clone_cell::clone::PureClone for Config<T>
+    where
+        T: ::clone_cell::clone::PureClone,
+        Rc<T>: ::clone_cell::clone::PureClone,
+    {
+    }
+    #[allow(dead_code)]
+    fn assert_fields_are_pure_clone<T>(_: &Config<T>)
+    where
+        T: ::clone_cell::clone::PureClone,
+        Rc<T>: ::clone_cell::clone::PureClone,
+    {
+        let _ = <Rc<str> as ::clone_cell::clone::PureClone>::pure_clone;
+        let _ = <u32 as ::clone_cell::clone::PureClone>::pure_clone;
+    }
+};
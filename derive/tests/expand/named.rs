@@ -0,0 +1,7 @@
+struct Config<T> {
+    name: Rc<str>,
+    retries: u32,
+    timeout: u32,
+    value: T,
+    shared: Rc<T>,
+}
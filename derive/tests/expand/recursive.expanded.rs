@@ -0,0 +1,26 @@
+const _: () = {
+    impl<T> ::core::clone::Clone for List<T>
+    where
+        T: ::core::clone::Clone,
+    {
+        fn clone(&self) -> Self {
+            List {
+                next: ::core::clone::Clone::clone(&self.next),
+                value: ::core::clone::Clone::clone(&self.value),
+            }
+        }
+        #[inline]
+        fn clone_from(&mut self, source: &Self) {
+            ::core::clone::Clone::clone_from(&mut self.next, &source.next);
+            ::core::clone::Clone::clone_from(&mut self.value, &source.value);
+        }
+    }
+    unsafe impl<T> ::clone_cell::clone::PureClone for List<T> where T: ::clone_cell::clone::PureClone {}
+    #[allow(dead_code)]
+    fn assert_fields_are_pure_clone<T>(_: &List<T>)
+    where
+        T: ::clone_cell::clone::PureClone,
+    {
+        let _ = <Option<Box<List<T>>> as ::clone_cell::clone::PureClone>::pure_clone;
+    }
+};
@@ -0,0 +1,4 @@
+struct List<T> {
+    next: Option<Box<Self>>,
+    value: T,
+}
@@ -0,0 +1,153 @@
+//! Implementation details of the [`PureClone`](derive@crate::clone::PureClone) derive macro and of
+//! the macros exported by this crate. This is not part of the public API.
+//!
+//! The derive macro asserts that a field is `PureClone` through one of the functions below when
+//! the field type looks like a type that is well known not to be `PureClone`. Each of them only
+//! changes the error message: any field type that is `PureClone` passes the assertion.
+
+#[cfg(feature = "alloc")]
+use core::ptr;
+
+#[cfg(feature = "alloc")]
+pub use alloc::boxed::Box;
+
+#[cfg(feature = "alloc")]
+use crate::clone::DynPureClone;
+use crate::clone::{PureClone, TrustedPureClone};
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be `PureClone` because `RefCell::clone` borrows at runtime",
+    label = "not `PureClone`",
+    note = "`RefCell::clone` cannot be proven pure; consider `Rc<RefCell<T>>` if you only need a \
+            shared handle, or `clone_cell::cell::Cell<T>`"
+)]
+pub trait RefCellField {}
+
+impl<T> RefCellField for T where T: PureClone {}
+
+#[inline]
+pub fn assert_ref_cell_field<T>()
+where
+    T: RefCellField,
+{
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be `PureClone` because locks cannot be cloned",
+    label = "not `PureClone`",
+    note = "consider wrapping the lock in an `Arc` if you only need a shared handle"
+)]
+pub trait LockField {}
+
+impl<T> LockField for T where T: PureClone {}
+
+#[inline]
+pub fn assert_lock_field<T>()
+where
+    T: LockField,
+{
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be `PureClone` because cloning a `Cell` reads its content",
+    label = "not `PureClone`",
+    note = "consider `Rc<Cell<T>>` if you only need a shared handle, or storing the value \
+            directly since the containing `Cell` already provides interior mutability"
+)]
+pub trait CellField {}
+
+impl<T> CellField for T where T: PureClone {}
+
+#[inline]
+pub fn assert_cell_field<T>()
+where
+    T: CellField,
+{
+}
+
+/// Unlike the other helpers, this takes the type inside the `Box`, because the error would
+/// otherwise be reported for the unsatisfied `T: PureClone` bound of `Box<T>`'s impl.
+#[cfg(feature = "alloc")]
+#[diagnostic::on_unimplemented(
+    message = "`Box<{Self}>` cannot be `PureClone` because boxed closures cannot be cloned",
+    label = "not `PureClone`",
+    note = "consider `Rc<{Self}>` or `Arc<{Self}>`, which are always `PureClone`"
+)]
+pub trait BoxedClosureField {}
+
+#[cfg(feature = "alloc")]
+impl<T> BoxedClosureField for T
+where
+    T: ?Sized,
+    Box<T>: PureClone,
+{
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn assert_boxed_closure_field<T>()
+where
+    T: BoxedClosureField + ?Sized,
+{
+}
+
+/// Clones a trait object whose trait has [`DynPureClone`] as a supertrait into a new `Box` of the
+/// same trait object type. This is used by
+/// [`pure_clone_trait_object!`](crate::pure_clone_trait_object).
+#[cfg(feature = "alloc")]
+pub fn pure_clone_box<T>(t: &T) -> Box<T>
+where
+    T: DynPureClone + ?Sized,
+{
+    let mut fat_ptr = ptr::from_ref(t);
+    let data_ptr = Box::into_raw(t.pure_clone_box()).cast::<()>();
+    // SAFETY: `pure_clone_box` returns a `Box` of the same type as `*t`, so replacing the data
+    // pointer of `fat_ptr` (its first word) with the new allocation leaves the metadata valid.
+    unsafe {
+        *ptr::from_mut(&mut fat_ptr).cast::<*mut ()>() = data_ptr;
+        Box::from_raw(fat_ptr.cast_mut())
+    }
+}
+
+#[inline]
+pub const fn assert_pure_clone<T>()
+where
+    T: PureClone,
+{
+}
+
+#[inline]
+pub fn assert_trusted_pure_clone<T>()
+where
+    T: TrustedPureClone,
+{
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not `Copy`, so `impl_pure_clone_for_copy!` cannot be used for it",
+    label = "not `Copy`",
+    note = "use `pure_clone_remote!` or the `PureClone` derive macro for types that are only `Clone`"
+)]
+pub trait CopyType {}
+
+impl<T> CopyType for T where T: Copy {}
+
+#[inline]
+pub const fn assert_copy<T>()
+where
+    T: CopyType,
+{
+}
+
+/// Implemented twice for each `PureClone` type, and once for every other type. Naming the item
+/// through this trait with an inferred type parameter therefore only compiles for types that are
+/// not `PureClone`. This is used by [`assert_not_pure_clone!`](crate::assert_not_pure_clone).
+pub trait AmbiguousIfPureClone<A> {
+    fn some_item() {}
+}
+
+impl<T> AmbiguousIfPureClone<()> for T where T: ?Sized {}
+
+pub struct Invalid;
+
+impl<T> AmbiguousIfPureClone<Invalid> for T where T: PureClone {}
@@ -11,15 +11,37 @@
 //! writer at a time. But the user can easily build zero-cost abstractions on top of a `Cell` to
 //! enforce this. For example, this may be useful when implementing the observer pattern.
 
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    rc::Rc,
+    string::String,
+    vec::Vec,
+};
 use core::{
     cell::UnsafeCell,
     cmp::Ordering,
     fmt,
-    fmt::{Debug, Formatter},
-    mem, ptr,
+    fmt::{Debug, Display, Formatter},
+    iter::{Product, Sum},
+    mem::{self, ManuallyDrop},
+    net::SocketAddr,
+    num::FpCategory,
+    ptr::{self, NonNull},
+    time::Duration,
 };
 
-use crate::clone::PureClone;
+#[cfg(feature = "alloc")]
+use crate::clone::PureToOwned;
+use crate::clone::{PureClone, PureEq, PureOrd};
+
+#[cfg(feature = "alloc")]
+pub use self::atomic_like::WeakSlot;
+pub use self::atomic_like::{Flag, Versioned};
+
+mod atomic_like;
+pub(crate) mod probe;
 
 /// A mutable memory location with a [`get`](Cell::get) method that works with
 /// [`PureClone`](crate::clone::PureClone) types.
@@ -35,6 +57,20 @@ use crate::clone::PureClone;
 /// x.set(Rc::new(42));
 /// assert_eq!(*x.get(), 42);
 /// ```
+// The zerocopy traits are derived through the field, which zerocopy implements them for.
+// `Immutable` is not, since `Cell` has interior mutability. zerocopy requires it for casting bytes
+// from `&[u8]` and into `&[u8]`, e.g., in `ref_from_bytes` and `as_bytes`. Those casts would let
+// `set` change bytes behind a shared `&[u8]`, or read through `get` bytes that a `&[u8]` elsewhere
+// assumes are frozen. Casting from and into `&mut [u8]` is still allowed.
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
 #[repr(transparent)]
 pub struct Cell<T>
 where
@@ -60,6 +96,22 @@ impl<T> Cell<T> {
         }
     }
 
+    /// Creates a new `Cell` from an `UnsafeCell` containing the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cell::UnsafeCell;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::from_unsafe_cell(UnsafeCell::new(42));
+    /// assert_eq!(c.get(), 42);
+    /// ```
+    #[inline]
+    pub const fn from_unsafe_cell(inner: UnsafeCell<T>) -> Self {
+        Self { value: inner }
+    }
+
     /// Sets the contained value.
     ///
     /// # Examples
@@ -113,6 +165,7 @@ impl<T> Cell<T> {
             panic!("`Cell::swap` on overlapping non-identical `Cell`s");
         }
 
+        probe::record_write();
         // SAFETY: Only safe because `Cell` is `!Sync`. Also, no pointers are
         // invalidated since `Cell` never returns references to its content.
         unsafe {
@@ -120,6 +173,51 @@ impl<T> Cell<T> {
         }
     }
 
+    /// Swaps the values of this `Cell` and a [`core::cell::Cell`]. This is useful when migrating
+    /// code where some cells are still `std` cells.
+    ///
+    /// `T` must be `Copy` so that the value can be moved between the two kinds of cells without
+    /// depending on how either of them handles cloning or dropping it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `other` are different cells that partially overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c1 = Cell::new(21);
+    /// let c2 = core::cell::Cell::new(42);
+    /// c1.swap_std(&c2);
+    /// assert_eq!(42, c1.get());
+    /// assert_eq!(21, c2.get());
+    /// ```
+    #[inline]
+    pub fn swap_std(&self, other: &core::cell::Cell<T>)
+    where
+        T: Copy,
+    {
+        let src_usize = self.value.get() as usize;
+        let dst_usize = other.as_ptr() as usize;
+        if src_usize == dst_usize {
+            return;
+        }
+
+        // Check if the two overlap.
+        let diff = src_usize.abs_diff(dst_usize);
+        if diff < size_of::<T>() {
+            panic!("`Cell::swap_std` on overlapping non-identical cells");
+        }
+
+        probe::record_write();
+        // SAFETY: Both cells are `!Sync`, and neither hands out references to its content.
+        unsafe {
+            ptr::swap(self.value.get(), other.as_ptr());
+        }
+    }
+
     /// Replaces the contained value with `value` and returns the old value.
     ///
     /// # Examples
@@ -134,6 +232,7 @@ impl<T> Cell<T> {
     /// assert_eq!(*c.get(), 2);
     /// ```
     pub fn replace(&self, value: T) -> T {
+        probe::record_write();
         // SAFETY: Only safe because `Cell` is `!Sync`.
         mem::replace(unsafe { &mut *self.value.get() }, value)
     }
@@ -148,9 +247,29 @@ impl<T> Cell<T> {
     /// let c = Cell::new(42);
     /// assert_eq!(c.into_inner(), 42);
     /// ```
-    // TODO: Mark `const` once stabilized.
-    pub fn into_inner(self) -> T {
-        self.value.into_inner()
+    #[inline]
+    pub const fn into_inner(self) -> T {
+        self.into_unsafe_cell().into_inner()
+    }
+
+    /// Unwraps the `UnsafeCell` containing the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(42);
+    /// assert_eq!(c.into_unsafe_cell().into_inner(), 42);
+    /// ```
+    #[inline]
+    pub const fn into_unsafe_cell(self) -> UnsafeCell<T> {
+        // Moving `self.value` out would drop the rest of `self`, which stable `const fn`s cannot
+        // do even though there is nothing left to drop.
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `Cell<T>` is `repr(transparent)` over `UnsafeCell<T>`, and `this` is never
+        // dropped, so the value is moved out exactly once.
+        unsafe { ptr::read(ptr::from_ref(&this).cast::<UnsafeCell<T>>()) }
     }
 
     /// Returns a copy of the contained value.
@@ -178,8 +297,162 @@ impl<T> Cell<T> {
         unsafe { (*self.value.get()).pure_clone() }
     }
 
-    // TODO:
-    // pub fn update
+    /// Returns a copy of the contained value. Unlike [`get`](Cell::get), this can be called in
+    /// `const` contexts, but requires `T` to be `Copy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// const fn squares() -> [Cell<u32>; 4] {
+    ///     let mut table = [const { Cell::new(0) }; 4];
+    ///     let mut i = 0;
+    ///     while i < table.len() {
+    ///         table[i] = Cell::new((i * i) as u32);
+    ///         i += 1;
+    ///     }
+    ///     table
+    /// }
+    ///
+    /// const NINE: u32 = squares()[3].get_copy();
+    /// assert_eq!(NINE, 9);
+    /// ```
+    #[inline]
+    pub const fn get_copy(&self) -> T
+    where
+        T: Copy,
+    {
+        // SAFETY: Only safe because `Cell` is `!Sync`. Copying does not run any user code.
+        unsafe { *self.value.get() }
+    }
+
+    /// Clones the contained value into `dst`, dropping the previous value of `dst`.
+    ///
+    /// This is a convenience for `*dst = cell.get()`, e.g., for reading into a reusable slot in a
+    /// loop. It has no performance benefit over that assignment: no allocation of `dst` is reused,
+    /// and for an `Rc` it performs the same reference count updates. Reusing the resources of `dst`
+    /// in place, as `Clone::clone_from` does, is not possible, because that would read the content
+    /// of this `Cell` while parts of `dst` are dropped, and dropping them may access this `Cell`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(Rc::new(1));
+    /// let mut frame = Rc::new(0);
+    /// for i in 1..=3 {
+    ///     c.get_into(&mut frame);
+    ///     assert_eq!(*frame, i);
+    ///     c.set(Rc::new(i + 1));
+    /// }
+    /// ```
+    #[inline]
+    pub fn get_into(&self, dst: &mut T)
+    where
+        T: PureClone,
+    {
+        *dst = self.get();
+    }
+
+    /// Returns the result of `f` applied to a clone of the contained value.
+    ///
+    /// This is a shorthand for `f(self.get())`. The `Cell` keeps its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(Rc::new(41));
+    /// assert_eq!(c.map_get(|x| *x + 1), 42);
+    /// assert_eq!(*c.get(), 41);
+    /// ```
+    #[inline]
+    pub fn map_get<U>(&self, f: impl FnOnce(T) -> U) -> U
+    where
+        T: PureClone,
+    {
+        f(self.get())
+    }
+
+    /// Updates the contained value using a function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(Rc::new(41));
+    /// c.update(|x| Rc::new(*x + 1));
+    /// assert_eq!(*c.get(), 42);
+    /// ```
+    #[inline]
+    pub fn update(&self, f: impl FnOnce(T) -> T)
+    where
+        T: PureClone,
+    {
+        let old = self.get();
+        self.set(f(old));
+    }
+
+    /// Updates the contained value using a function that can fail.
+    ///
+    /// The contained value is cloned and passed to `f`. If `f` returns `Ok`, the new value is
+    /// stored. If it returns `Err`, the `Cell` is left untouched and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(Rc::new(41));
+    /// assert_eq!(c.try_update(|x| Ok::<_, ()>(Rc::new(*x + 1))), Ok(()));
+    /// assert_eq!(*c.get(), 42);
+    /// assert_eq!(c.try_update(|_| Err("overflow")), Err("overflow"));
+    /// assert_eq!(*c.get(), 42);
+    /// ```
+    #[inline]
+    pub fn try_update<E>(&self, f: impl FnOnce(T) -> Result<T, E>) -> Result<(), E>
+    where
+        T: PureClone,
+    {
+        let new = f(self.get())?;
+        self.set(new);
+        Ok(())
+    }
+
+    /// Updates the contained value using a function, and returns both the old and the new value.
+    ///
+    /// This clones twice: once to get the old value, which is passed to `f` by reference, and once
+    /// to store the new value computed by `f` while returning it as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(Rc::new(41));
+    /// let (old, new) = c.replace_returning_both(|x| Rc::new(**x + 1));
+    /// assert_eq!((*old, *new), (41, 42));
+    /// assert!(Rc::ptr_eq(&new, &c.get()));
+    /// ```
+    #[inline]
+    pub fn replace_returning_both(&self, f: impl FnOnce(&T) -> T) -> (T, T)
+    where
+        T: PureClone,
+    {
+        let old = self.get();
+        let new = f(&old);
+        self.set(new.pure_clone());
+        (old, new)
+    }
 
     /// Takes the value of the `Cell`, leaving a `Default::default()` in its place.
     ///
@@ -199,6 +472,57 @@ impl<T> Cell<T> {
     {
         self.replace(Default::default())
     }
+
+    /// Takes the value of the `Cell`, leaving a `Default::default()` in its place, and passes it
+    /// to `f`. Returns the result of `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(vec![1, 2, 3]);
+    /// let len = c.take_and(|v| v.len());
+    /// assert_eq!(len, 3);
+    /// assert!(c.take().is_empty());
+    /// ```
+    pub fn take_and<R>(&self, f: impl FnOnce(T) -> R) -> R
+    where
+        T: Default,
+    {
+        f(self.take())
+    }
+
+    /// Takes the value of the `Cell` if `predicate` returns `true` for it, leaving a
+    /// `Default::default()` in its place. Otherwise, the value is put back and `None` is returned.
+    ///
+    /// The value is taken out before `predicate` runs, so the value that `predicate` tests is
+    /// always the one returned, and the `Cell` holds the default value while `predicate` runs. A
+    /// value set by `predicate` is dropped when the tested value is put back. If `predicate`
+    /// panics, the `Cell` is left with the default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(vec![1, 2, 3]);
+    /// assert_eq!(c.take_if(|v| v.len() > 3), None);
+    /// assert_eq!(c.take_if(|v| v.len() == 3), Some(vec![1, 2, 3]));
+    /// assert!(c.get().is_empty());
+    /// ```
+    pub fn take_if(&self, predicate: impl FnOnce(&T) -> bool) -> Option<T>
+    where
+        T: Default,
+    {
+        let value = self.take();
+        if predicate(&value) {
+            Some(value)
+        } else {
+            self.set(value);
+            None
+        }
+    }
 }
 
 impl<T> Cell<T>
@@ -237,6 +561,149 @@ where
         self.value.get_mut()
     }
 
+    /// Returns a shared reference to the underlying data without cloning it. Like
+    /// [`get_mut`](Cell::get_mut), this requires `&mut self`, so the value cannot be changed while
+    /// the reference is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let mut c = Cell::new(vec![1, 2, 3]);
+    /// assert_eq!(c.peek().len(), 3);
+    /// ```
+    #[inline]
+    pub fn peek(&mut self) -> &T {
+        self.value.get_mut()
+    }
+
+    /// Calls `f` with a shared reference to the contained value and returns its result. Unlike
+    /// [`get`](Cell::get), this does not clone the value.
+    ///
+    /// # Safety
+    ///
+    /// `f` must not mutate the content of this `Cell` in any way (such as by calling
+    /// [`set`](Cell::set), [`replace`](Cell::replace), [`swap`](Cell::swap), or
+    /// [`take`](Cell::take) on it, directly or through some other shared pointer), because that
+    /// would invalidate the reference `f` is given. This includes paths through the reference
+    /// itself, such as an `Rc` in the value that leads back to this `Cell`. See the
+    /// [crate#no-safe-scoped-reads] doc on why there is no safe version of this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(vec![1, 2, 3]);
+    /// // SAFETY: The closure does not touch `c`.
+    /// let len = unsafe { c.with(|v| v.len()) };
+    /// assert_eq!(len, 3);
+    /// ```
+    #[doc(alias = "inspect")]
+    #[inline]
+    pub unsafe fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        // SAFETY: The caller guarantees the content is not mutated while `f` runs.
+        f(unsafe { &*self.value.get() })
+    }
+
+    /// Returns a wrapper that formats the contained value with its [`Debug`] impl, without cloning
+    /// it.
+    ///
+    /// Unlike the `Debug` impl of `Cell`, which formats a clone, the wrapper reads the value in
+    /// place each time it is formatted. This is useful to print large values, such as a long `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The `Debug` impl of `T` must not mutate the content of this `Cell`, for as long as the
+    /// returned wrapper is in use. See [`with`](Cell::with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(vec![1, 2, 3]);
+    /// // SAFETY: The `Debug` impl of `Vec<i32>` does not touch `c`.
+    /// let s = format!("{:?}", unsafe { c.debug_peek() });
+    /// assert_eq!(s, "[1, 2, 3]");
+    /// ```
+    #[inline]
+    pub unsafe fn debug_peek(&self) -> impl Debug + '_
+    where
+        T: Debug,
+    {
+        DebugPeek(self)
+    }
+
+    /// Returns whether the contained values of `self` and `other` are equal, without cloning them.
+    ///
+    /// This is the same as `self == other`, except that `==` compares clones of both values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let a = Cell::new(vec![1u8, 2, 3]);
+    /// let b = Cell::new(vec![1u8, 2, 3]);
+    /// assert!(a.pure_eq(&b));
+    /// b.set(vec![]);
+    /// assert!(!a.pure_eq(&b));
+    /// ```
+    #[inline]
+    pub fn pure_eq(&self, other: &Self) -> bool
+    where
+        T: PureEq,
+    {
+        // SAFETY: `T: PureEq` guarantees that `eq` does not mutate the content of either `Cell`.
+        unsafe { *self.value.get() == *other.value.get() }
+    }
+
+    /// Compares the contained values of `self` and `other` with [`PartialOrd`], without cloning
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let a = Cell::new(1.0);
+    /// assert_eq!(a.pure_partial_cmp(&Cell::new(2.0)), Some(Ordering::Less));
+    /// assert_eq!(a.pure_partial_cmp(&Cell::new(f64::NAN)), None);
+    /// ```
+    #[inline]
+    pub fn pure_partial_cmp(&self, other: &Self) -> Option<Ordering>
+    where
+        T: PureOrd,
+    {
+        // SAFETY: `T: PureOrd` guarantees that `partial_cmp` does not mutate the content of either
+        // `Cell`.
+        unsafe { (*self.value.get()).partial_cmp(&*other.value.get()) }
+    }
+
+    /// Compares the contained values of `self` and `other` with [`Ord`], without cloning them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let a = Cell::new(String::from("apple"));
+    /// let b = Cell::new(String::from("banana"));
+    /// assert_eq!(a.pure_cmp(&b), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn pure_cmp(&self, other: &Self) -> Ordering
+    where
+        T: Ord + PureOrd,
+    {
+        // SAFETY: `T: PureOrd` guarantees that `cmp` does not mutate the content of either `Cell`.
+        unsafe { (*self.value.get()).cmp(&*other.value.get()) }
+    }
+
     /// Returns a `&Cell<T>` from a `&mut T`.
     ///
     /// # Examples
@@ -277,9 +744,586 @@ impl<T> Cell<[T]> {
         // SAFETY: `Cell<T>` has the same memory layout as `T`.
         unsafe { &*(self as *const Self as *const [Cell<T>]) }
     }
-}
 
-// TODO: Implement CoerceUnsized
+    /// Swaps the elements of two `Cell<[T]>`s element by element. Unlike `<[T]>::swap_with_slice`,
+    /// this does not require a `&mut` reference.
+    ///
+    /// Swapping a slice with itself is a no-op.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths, or if `self` and `other` are different
+    /// slices that partially overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let mut front = [1, 2, 3];
+    /// let mut back = [4, 5, 6];
+    /// let front: &Cell<[u8]> = Cell::from_mut(&mut front[..]);
+    /// let back: &Cell<[u8]> = Cell::from_mut(&mut back[..]);
+    /// front.swap_with(back);
+    /// assert_eq!(front.as_slice_of_cells()[0].get(), 4);
+    /// assert_eq!(back.as_slice_of_cells()[2].get(), 3);
+    /// ```
+    pub fn swap_with(&self, other: &Self) {
+        let len = self.as_slice_of_cells().len();
+        assert_eq!(
+            len,
+            other.as_slice_of_cells().len(),
+            "`Cell::swap_with` on slices of different lengths",
+        );
+        if ptr::eq(self, other) {
+            return;
+        }
+
+        // Check if the two overlap.
+        let src_usize = self as *const Self as *const T as usize;
+        let dst_usize = other as *const Self as *const T as usize;
+        let diff = src_usize.abs_diff(dst_usize);
+        if diff < mem::size_of_val(self) {
+            panic!("`Cell::swap_with` on overlapping non-identical slices");
+        }
+
+        probe::record_write();
+        // SAFETY: Only safe because `Cell` is `!Sync`. Also, no pointers are
+        // invalidated since `Cell` never returns references to its content. The two slices have
+        // the same length and were checked not to overlap above.
+        unsafe {
+            ptr::swap_nonoverlapping(self.value.get() as *mut T, other.value.get() as *mut T, len);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Cell<Vec<T>> {
+    /// Returns a `&[Cell<T>]` viewing each element of the contained `Vec`.
+    ///
+    /// Unlike [`Cell<[T]>::as_slice_of_cells`](Cell::as_slice_of_cells), this requires `&mut self`.
+    /// With only a shared reference, the `Vec` could be replaced or reallocated through the `Cell`
+    /// (e.g., by [`set`](Cell::set)) while the returned slice still points to its old buffer. The
+    /// exclusive borrow rules this out for as long as the slice is in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let mut c = Cell::new(vec![Rc::new(0), Rc::new(1)]);
+    /// let cells = c.as_slice_of_cells();
+    /// cells[0].set(cells[1].get());
+    /// assert_eq!(*cells[0].get(), 1);
+    /// ```
+    #[inline]
+    pub fn as_slice_of_cells(&mut self) -> &[Cell<T>] {
+        Cell::from_mut(self.get_mut().as_mut_slice()).as_slice_of_cells()
+    }
+
+    /// Sorts the contained `Vec` in place. This is a shorthand for `self.get_mut().sort()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let mut c = Cell::new(vec![3, 1, 2]);
+    /// c.sort();
+    /// assert_eq!(c.get(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.get_mut().sort();
+    }
+
+    /// Removes consecutive repeated elements from the contained `Vec` in place. This is a shorthand
+    /// for `self.get_mut().dedup()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let mut c = Cell::new(vec![1, 1, 2, 1]);
+    /// c.dedup();
+    /// assert_eq!(c.get(), [1, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.get_mut().dedup();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Cell<Vec<T>>
+where
+    T: PureClone,
+{
+    /// Clones each element of the contained `Vec` and appends it to `dst`. Unlike
+    /// [`get`](Cell::get), this reuses the capacity of `dst` instead of allocating a new `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(vec![Rc::new(1), Rc::new(2)]);
+    /// let mut v = Vec::with_capacity(4);
+    /// c.clone_into_vec(&mut v);
+    /// c.clone_into_vec(&mut v);
+    /// assert_eq!(v.len(), 4);
+    /// assert_eq!(*v[3], 2);
+    /// ```
+    pub fn clone_into_vec(&self, dst: &mut Vec<T>) {
+        // SAFETY: Only safe because `Cell` is `!Sync`, and `pure_clone` does not mutate the
+        // content. `dst` cannot alias the content since `Cell` never hands out references to it.
+        let src = unsafe { &*self.value.get() };
+        dst.extend(src.iter().map(PureClone::pure_clone));
+    }
+}
+
+/// Unlike [`update`](Cell::update), these methods take the `String` out of the `Cell` instead of
+/// cloning it, and put it back once it is modified. This does not allocate, unless the `String`
+/// has to grow, and no user code can observe the `Cell` in between.
+#[cfg(feature = "alloc")]
+impl Cell<String> {
+    /// Appends `c` to the end of the contained `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(String::from("ab"));
+    /// c.push('c');
+    /// assert_eq!(c.get(), "abc");
+    /// ```
+    #[inline]
+    pub fn push(&self, c: char) {
+        let mut s = self.take();
+        s.push(c);
+        self.set(s);
+    }
+
+    /// Appends `string` to the end of the contained `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(String::from("foo"));
+    /// c.push_str("bar");
+    /// assert_eq!(c.get(), "foobar");
+    /// ```
+    #[inline]
+    pub fn push_str(&self, string: &str) {
+        let mut s = self.take();
+        s.push_str(string);
+        self.set(s);
+    }
+
+    /// Shortens the contained `String` to `new_len` bytes. This has no effect if `new_len` is
+    /// greater than the current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a `char` boundary. The `Cell` is left empty in that
+    /// case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(String::from("hello"));
+    /// c.truncate(2);
+    /// assert_eq!(c.get(), "he");
+    /// ```
+    #[inline]
+    pub fn truncate(&self, new_len: usize) {
+        let mut s = self.take();
+        s.truncate(new_len);
+        self.set(s);
+    }
+}
+
+impl<T> Cell<Option<T>> {
+    /// Creates a new `Cell` containing `None`. This is the same as `Cell::new(None)`.
+    ///
+    /// `Cell<Option<T>>` is also `Default` for any `T`. This is useful for deriving `Default` for a
+    /// struct with a field such as `Cell<Rc<T>>`, which cannot be `Default` because `Rc<T>` is not:
+    /// storing a `Cell<Option<Rc<T>>>` instead makes the field `Default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// struct Node;
+    ///
+    /// #[derive(Default)]
+    /// struct Parent {
+    ///     child: Cell<Option<Rc<Node>>>,
+    /// }
+    ///
+    /// let p = Parent::default();
+    /// assert!(p.child.get().is_none());
+    ///
+    /// let c = Cell::<Option<Rc<Node>>>::none();
+    /// assert!(c.get().is_none());
+    /// ```
+    #[inline]
+    pub const fn none() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<T> Cell<Option<NonNull<T>>>
+where
+    T: ?Sized,
+{
+    /// Returns whether the contained pointer is `None`, i.e., null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ptr::NonNull;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let mut x = 42;
+    /// let c = Cell::new(None);
+    /// assert!(c.is_null());
+    /// c.set(Some(NonNull::from(&mut x)));
+    /// assert!(!c.is_null());
+    /// ```
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.get().is_none()
+    }
+
+    /// Stores `ptr`, or `None` if it is null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ptr;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let mut x = 42;
+    /// let c = Cell::none();
+    /// c.set_ptr(&mut x);
+    /// assert_eq!(c.get().unwrap().as_ptr(), &mut x as *mut i32);
+    /// c.set_ptr(ptr::null_mut());
+    /// assert!(c.is_null());
+    /// ```
+    #[inline]
+    pub fn set_ptr(&self, ptr: *mut T) {
+        self.set(NonNull::new(ptr));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B> Cell<&B>
+where
+    B: PureToOwned + ?Sized,
+{
+    /// Returns an owned copy of the value the contained reference points to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new("foo");
+    /// let s: String = c.get_owned();
+    /// c.set("bar");
+    /// assert_eq!(s, "foo");
+    /// assert_eq!(c.get_owned(), "bar");
+    /// ```
+    #[inline]
+    pub fn get_owned(&self) -> B::Owned {
+        self.get().to_owned()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B> Cell<Cow<'_, B>>
+where
+    B: PureToOwned + ?Sized,
+{
+    /// Returns an owned copy of the contained value, whether it is borrowed or owned.
+    ///
+    /// Unlike `self.get().into_owned()`, this never clones the value twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(Cow::Borrowed("foo"));
+    /// assert_eq!(c.get_owned(), "foo");
+    /// c.set(Cow::Owned(String::from("bar")));
+    /// assert_eq!(c.get_owned(), "bar");
+    /// ```
+    pub fn get_owned(&self) -> B::Owned {
+        // SAFETY: Only safe because `Cell` is `!Sync`, and neither the `Borrow` impl of `B::Owned`
+        // (called by `Deref`) nor `to_owned` mutate the content.
+        let cow = unsafe { &*self.value.get() };
+        (**cow).to_owned()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Cell<Option<Rc<T>>>
+where
+    T: ?Sized,
+{
+    /// Returns a clone of the contained `Rc`, or initializes it with `f` if the `Cell` is `None`.
+    ///
+    /// If `f` itself sets the `Cell`, that value is overwritten by the one `f` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(None);
+    /// let p = c.get_or_init(|| Rc::new(42));
+    /// assert_eq!(*p, 42);
+    /// assert_eq!(*c.get_or_init(|| Rc::new(0)), 42);
+    /// assert_eq!(Rc::strong_count(&p), 2);
+    /// ```
+    pub fn get_or_init(&self, f: impl FnOnce() -> Rc<T>) -> Rc<T> {
+        if let Some(p) = self.get() {
+            return p;
+        }
+        let p = f();
+        self.set(Some(p.clone()));
+        p
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Cell<BTreeSet<T>>
+where
+    T: Ord + PureClone,
+{
+    /// Inserts `value` into a clone of the contained set, and stores the clone back if it changed.
+    /// Returns whether the value was newly inserted.
+    ///
+    /// This clones the whole set, which takes O(n) time. The set cannot be searched in place,
+    /// because that would call `Ord` on the content, which is not guaranteed to be pure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(BTreeSet::new());
+    /// assert!(c.insert_cloned("dark-mode"));
+    /// assert!(!c.insert_cloned("dark-mode"));
+    /// assert_eq!(c.get().len(), 1);
+    /// ```
+    pub fn insert_cloned(&self, value: T) -> bool {
+        let mut set = self.get();
+        let inserted = set.insert(value);
+        if inserted {
+            self.set(set);
+        }
+        inserted
+    }
+
+    /// Removes `value` from a clone of the contained set, and stores the clone back if it changed.
+    /// Returns whether the value was present.
+    ///
+    /// This clones the whole set, which takes O(n) time. See
+    /// [`insert_cloned`](Cell::insert_cloned) for why.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(BTreeSet::from(["dark-mode"]));
+    /// assert!(c.remove_cloned(&"dark-mode"));
+    /// assert!(!c.remove_cloned(&"dark-mode"));
+    /// assert!(c.get().is_empty());
+    /// ```
+    pub fn remove_cloned(&self, value: &T) -> bool {
+        let mut set = self.get();
+        let removed = set.remove(value);
+        if removed {
+            self.set(set);
+        }
+        removed
+    }
+}
+
+impl Cell<Ordering> {
+    /// Reverses the contained ordering: `Less` becomes `Greater`, `Greater` becomes `Less`, and
+    /// `Equal` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(Ordering::Less);
+    /// c.reverse();
+    /// assert_eq!(c.get(), Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reverse(&self) {
+        self.set(self.get().reverse());
+    }
+}
+
+impl Cell<Duration> {
+    /// Adds `rhs` to the contained duration. Returns `true` on success, or `false` if the sum
+    /// overflows, in which case the `Cell` is left untouched. The sum itself is computed with
+    /// [`Duration::checked_add`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(Duration::from_secs(1));
+    /// assert!(c.try_add_assign(Duration::from_millis(500)));
+    /// assert_eq!(c.get(), Duration::from_millis(1500));
+    /// assert!(!c.try_add_assign(Duration::MAX));
+    /// assert_eq!(c.get(), Duration::from_millis(1500));
+    /// ```
+    #[doc(alias = "checked_add")]
+    #[inline]
+    pub fn try_add_assign(&self, rhs: Duration) -> bool {
+        match self.get().checked_add(rhs) {
+            Some(sum) => {
+                self.set(sum);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Cell<SocketAddr> {
+    /// Returns the port number of the contained socket address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::SocketAddr;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(SocketAddr::from(([127, 0, 0, 1], 8080)));
+    /// assert_eq!(c.port(), 8080);
+    /// ```
+    #[inline]
+    pub fn port(&self) -> u16 {
+        self.get().port()
+    }
+
+    /// Changes the port number of the contained socket address, keeping its IP address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::SocketAddr;
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(SocketAddr::from(([127, 0, 0, 1], 8080)));
+    /// c.set_port(8081);
+    /// assert_eq!(c.get(), SocketAddr::from(([127, 0, 0, 1], 8081)));
+    /// ```
+    #[inline]
+    pub fn set_port(&self, port: u16) {
+        let mut addr = self.get();
+        addr.set_port(port);
+        self.set(addr);
+    }
+}
+
+impl Cell<[u8; 4]> {
+    /// Returns the contained bytes as a big-endian `u32`, e.g., to use a FourCC code as a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(*b"ftyp");
+    /// assert_eq!(c.as_u32_be(), 0x6674_7970);
+    /// ```
+    #[inline]
+    pub fn as_u32_be(&self) -> u32 {
+        u32::from_be_bytes(self.get())
+    }
+
+    /// Returns whether the contained bytes are equal to `tag`, e.g., to check a FourCC code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Cell;
+    ///
+    /// let c = Cell::new(*b"moov");
+    /// assert!(c.matches(b"moov"));
+    /// c.set(*b"mdat");
+    /// assert!(!c.matches(b"moov"));
+    /// ```
+    #[inline]
+    pub fn matches(&self, tag: &[u8; 4]) -> bool {
+        self.get() == *tag
+    }
+}
+
+macro_rules! impl_float_cell {
+    ($($t:ident)*) => {
+        $(
+            impl Cell<$t> {
+                /// Returns the floating point category of the contained number.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use std::num::FpCategory;
+                /// use clone_cell::cell::Cell;
+                ///
+                #[doc = concat!("let c = Cell::new(", stringify!($t), "::NAN);")]
+                /// assert_eq!(c.classify(), FpCategory::Nan);
+                /// c.set(0.0);
+                /// assert_eq!(c.classify(), FpCategory::Zero);
+                /// ```
+                #[inline]
+                pub fn classify(&self) -> FpCategory {
+                    self.get().classify()
+                }
+            }
+        )*
+    };
+}
+
+impl_float_cell! { f32 f64 }
+
+// TODO: Implement CoerceUnsized
 
 impl<T> Clone for Cell<T>
 where
@@ -289,6 +1333,11 @@ where
     fn clone(&self) -> Self {
         Self::new(self.get())
     }
+
+    // `clone_from` is not overridden. Reusing the resources of `self` would need to read the
+    // content of `source` in place while parts of the old content of `self` are dropped, which is
+    // unsound, because their `Drop` impls could mutate `source`. Cloning `source` out first
+    // allocates as much as the default `*self = source.clone()` does, so there is nothing to gain.
 }
 
 impl<T> Debug for Cell<T>
@@ -300,6 +1349,69 @@ where
     }
 }
 
+/// Formats the elements one at a time, cloning each of them.
+impl<T> Debug for Cell<[T]>
+where
+    T: Debug + PureClone,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Cell")
+            .field("value", &DebugSlice(self.as_slice_of_cells()))
+            .finish()
+    }
+}
+
+/// Formats a `&[Cell<T>]` like a `&[T]`.
+struct DebugSlice<'a, T>(&'a [Cell<T>]);
+
+impl<T> Debug for DebugSlice<'_, T>
+where
+    T: Debug + PureClone,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(Cell::get))
+            .finish()
+    }
+}
+
+/// Formats a copy of the contained string.
+#[cfg(feature = "alloc")]
+impl Debug for Cell<str> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // SAFETY: Only safe because `Cell` is `!Sync`. The string is copied before any formatting
+        // code runs.
+        let value = unsafe { String::from(&*self.as_ptr()) };
+        f.debug_struct("Cell").field("value", &value).finish()
+    }
+}
+
+/// Returned by [`Cell::debug_peek`].
+struct DebugPeek<'a, T>(&'a Cell<T>)
+where
+    T: ?Sized;
+
+impl<T> Debug for DebugPeek<'_, T>
+where
+    T: Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // SAFETY: The caller of `debug_peek` guarantees that `Debug::fmt` does not mutate the
+        // content.
+        unsafe { self.0.with(|value| Debug::fmt(value, f)) }
+    }
+}
+
+/// Formats a clone of the contained value.
+impl<T> Display for Cell<T>
+where
+    T: Display + PureClone,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
 impl<T> Default for Cell<T>
 where
     T: Default,
@@ -316,6 +1428,44 @@ impl<T> From<T> for Cell<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
+macro_rules! impl_from_iterator_cell {
+    ($([$($g:tt)*] $c:ty, $item:ty: $example_ty:ty = $from:expr => $expected:expr;)*) => {
+        $(
+            #[doc = concat!("Collects the items into a `", stringify!($c), "` in a new `Cell`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use clone_cell::cell::Cell;
+            ///
+            #[doc = concat!(
+                "let c: Cell<", stringify!($example_ty), "> = ",
+                stringify!($from), ".into_iter().collect();",
+            )]
+            #[doc = concat!("assert_eq!(c.into_inner(), ", stringify!($expected), ");")]
+            /// ```
+            impl<$($g)*> FromIterator<$item> for Cell<$c> {
+                fn from_iter<I: IntoIterator<Item = $item>>(iter: I) -> Self {
+                    Self::new(iter.into_iter().collect())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_from_iterator_cell! {
+    [T] Vec<T>, T: Vec<i32> = [1, 2, 3] => [1, 2, 3];
+    [T] VecDeque<T>, T: std::collections::VecDeque<i32> = [1, 2, 3] => [1, 2, 3];
+    [K: Ord, V] BTreeMap<K, V>, (K, V): std::collections::BTreeMap<&str, i32> = [("b", 2), ("a", 1)]
+        => std::collections::BTreeMap::from([("a", 1), ("b", 2)]);
+    [] String, char: String = ['a', 'b', 'c'] => "abc";
+    ['a] String, &'a str: String = ["ab", "cd"] => "abcd";
+    [] String, String: String = [String::from("ab"), String::from("cd")] => "abcd";
+}
+
+/// Compares clones of both values. [`Cell::pure_eq`] compares them in place for [`PureEq`] types.
 impl<T> PartialEq for Cell<T>
 where
     T: PartialEq + PureClone,
@@ -328,6 +1478,8 @@ where
 
 impl<T> Eq for Cell<T> where T: Eq + PureClone {}
 
+/// Compares clones of both values. [`Cell::pure_partial_cmp`] compares them in place for
+/// [`PureOrd`] types.
 impl<T> PartialOrd for Cell<T>
 where
     T: PartialOrd + PureClone,
@@ -358,6 +1510,7 @@ where
     }
 }
 
+/// Compares clones of both values. [`Cell::pure_cmp`] compares them in place for [`PureOrd`] types.
 impl<T> Ord for Cell<T>
 where
     T: Ord + PureClone,
@@ -367,3 +1520,480 @@ where
         self.get().cmp(&other.get())
     }
 }
+
+/// Compares a clone of the contained value with `other`.
+///
+/// Only `Cell<T> == T` is supported. The orphan rule does not allow implementing
+/// `PartialEq<Cell<T>>` for every `T`.
+impl<T> PartialEq<T> for Cell<T>
+where
+    T: PartialEq + PureClone,
+{
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.get() == *other
+    }
+}
+
+/// Compares a clone of the contained value with `other`, e.g., `cell < 10`.
+///
+/// Only `Cell<T> < T` is supported. The orphan rule does not allow implementing
+/// `PartialOrd<Cell<T>>` for every `T`.
+impl<T> PartialOrd<T> for Cell<T>
+where
+    T: PartialOrd + PureClone,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.get().partial_cmp(other)
+    }
+
+    #[inline]
+    fn lt(&self, other: &T) -> bool {
+        self.get() < *other
+    }
+
+    #[inline]
+    fn le(&self, other: &T) -> bool {
+        self.get() <= *other
+    }
+
+    #[inline]
+    fn gt(&self, other: &T) -> bool {
+        self.get() > *other
+    }
+
+    #[inline]
+    fn ge(&self, other: &T) -> bool {
+        self.get() >= *other
+    }
+}
+
+// `Sum<Cell<T>>` and `Product<Cell<T>>` cannot be implemented for every `T` because of the orphan
+// rule, so folding into a bare `T` needs `map(Cell::into_inner)` or `map(Cell::get)` first. Folding
+// into a `Cell<T>` works for `T`, `Cell<T>` and `&Cell<T>` items.
+macro_rules! impl_fold_cell {
+    ($($trait:ident $method:ident $doc:literal,)*) => {
+        $(
+            #[doc = concat!("Wraps the ", $doc, " of the items in a new `Cell`.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use clone_cell::cell::Cell;
+            ///
+            #[doc = concat!("let c: Cell<u32> = [2, 3].into_iter().", stringify!($method), "();")]
+            #[doc = concat!(
+                "assert_eq!(c.get(), [2, 3].into_iter().", stringify!($method), "::<u32>());",
+            )]
+            /// ```
+            impl<T> $trait<T> for Cell<T>
+            where
+                T: $trait,
+            {
+                fn $method<I: Iterator<Item = T>>(iter: I) -> Self {
+                    Self::new(iter.$method())
+                }
+            }
+
+            #[doc = concat!("Wraps the ", $doc, " of the contained values in a new `Cell`.")]
+            impl<T> $trait for Cell<T>
+            where
+                T: $trait,
+            {
+                fn $method<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    Self::new(iter.map(Cell::into_inner).$method())
+                }
+            }
+
+            #[doc = concat!(
+                "Wraps the ", $doc, " of the contained values in a new `Cell`. ",
+                "Each value is cloned with [`get`](Cell::get).",
+            )]
+            impl<'a, T> $trait<&'a Cell<T>> for Cell<T>
+            where
+                T: $trait + PureClone,
+            {
+                fn $method<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    Self::new(iter.map(Cell::get).$method())
+                }
+            }
+        )*
+    };
+}
+
+impl_fold_cell! {
+    Sum sum "sum",
+    Product product "product",
+}
+
+/// Serializes a clone of the contained value, so that no reference into the `Cell` is held while
+/// the `Serialize` impl of `T` runs. The format is the same as that of `T`.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Cell<T>
+where
+    T: serde::Serialize + PureClone,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+/// Deserializes a `T` and wraps it in a `Cell`. The format is the same as that of `T`.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Cell<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Cell::new)
+    }
+}
+
+/// Generates an arbitrary `T` and wraps it in a `Cell`.
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for Cell<T>
+where
+    T: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u).map(Cell::new)
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary_take_rest(u).map(Cell::new)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+/// Generates a `T` and wraps it in a `Cell`. Shrinking shrinks the contained value.
+#[cfg(feature = "proptest")]
+impl<T> proptest::arbitrary::Arbitrary for Cell<T>
+where
+    T: proptest::arbitrary::Arbitrary + PureClone,
+{
+    type Parameters = T::Parameters;
+    type Strategy = proptest::strategy::Map<T::Strategy, fn(T) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(T::arbitrary_with(args), Cell::new)
+    }
+}
+
+/// Returns a proptest strategy that wraps the values generated by `inner` in a `Cell`.
+///
+/// This is the counterpart of `any::<Cell<T>>()` for a custom strategy of `T`. Shrinking shrinks
+/// the contained value.
+///
+/// # Examples
+///
+/// ```
+/// use clone_cell::cell::cell_strategy;
+/// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+///
+/// let mut runner = TestRunner::deterministic();
+/// let c = cell_strategy(1..10u8).new_tree(&mut runner).unwrap().current();
+/// assert!((1..10).contains(&c.get()));
+/// ```
+#[cfg(feature = "proptest")]
+pub fn cell_strategy<S>(inner: S) -> impl proptest::strategy::Strategy<Value = Cell<S::Value>>
+where
+    S: proptest::strategy::Strategy,
+    S::Value: PureClone,
+{
+    proptest::strategy::Strategy::prop_map(inner, Cell::new)
+}
+
+/// Generates a `T` and wraps it in a `Cell`. Shrinking shrinks a clone of the contained value.
+#[cfg(feature = "quickcheck")]
+impl<T> quickcheck::Arbitrary for Cell<T>
+where
+    T: quickcheck::Arbitrary + PureClone,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Cell::new(T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.get().shrink().map(Cell::new))
+    }
+}
+
+// `TransparentWrapper` is not implemented, although `Cell<T>` is `repr(transparent)` over `T`. Its
+// `wrap_ref` would turn a `&T` into a `&Cell<T>` that can mutate the value behind other shared
+// references, and its `peel_ref` would hand out a `&T` that `set` can invalidate. `Pod`,
+// `AnyBitPattern` and `NoUninit` all require `Copy`. This matches what `bytemuck` implements for
+// `core::cell::Cell`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Cell<T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for Cell<T>
+where
+    T: defmt::Format + PureClone,
+{
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Cell {{ value: {} }}", self.get());
+    }
+}
+
+/// The archived form of a `Cell<T>`, which has the same layout as the archived `T`.
+#[cfg(feature = "rkyv")]
+#[repr(transparent)]
+pub struct ArchivedCell<A>(A);
+
+#[cfg(feature = "rkyv")]
+impl<A> core::ops::Deref for ArchivedCell<A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        &self.0
+    }
+}
+
+// SAFETY: `ArchivedCell<A>` is `repr(transparent)` over `A`.
+#[cfg(feature = "rkyv")]
+unsafe impl<A> rkyv::Portable for ArchivedCell<A> where A: rkyv::Portable {}
+
+// SAFETY: `ArchivedCell<A>` is `repr(transparent)` over `A`, so it is valid whenever `A` is.
+#[cfg(feature = "rkyv")]
+unsafe impl<A, C> rkyv::bytecheck::CheckBytes<C> for ArchivedCell<A>
+where
+    A: rkyv::bytecheck::CheckBytes<C>,
+    C: rkyv::rancor::Fallible + ?Sized,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        A::check_bytes(value.cast(), context)
+    }
+}
+
+/// The resolver for a `Cell<T>`. It holds the value cloned out by `serialize`, so that `resolve`
+/// does not read the `Cell` again.
+#[cfg(feature = "rkyv")]
+pub struct CellResolver<T>
+where
+    T: rkyv::Archive,
+{
+    value: T,
+    resolver: T::Resolver,
+}
+
+#[cfg(feature = "rkyv")]
+impl<T> rkyv::Archive for Cell<T>
+where
+    T: rkyv::Archive + PureClone,
+{
+    type Archived = ArchivedCell<T::Archived>;
+    type Resolver = CellResolver<T>;
+
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        // SAFETY: `ArchivedCell<A>` is `repr(transparent)` over `A`.
+        let out = unsafe { out.cast_unchecked::<T::Archived>() };
+        resolver.value.resolve(resolver.resolver, out);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, S> rkyv::Serialize<S> for Cell<T>
+where
+    T: rkyv::Serialize<S> + PureClone,
+    S: rkyv::rancor::Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let value = self.get();
+        let resolver = value.serialize(serializer)?;
+        Ok(CellResolver { value, resolver })
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, D> rkyv::Deserialize<Cell<T>, D> for ArchivedCell<T::Archived>
+where
+    T: rkyv::Archive + PureClone,
+    T::Archived: rkyv::Deserialize<T, D>,
+    D: rkyv::rancor::Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Cell<T>, D::Error> {
+        self.0.deserialize(deserializer).map(Cell::new)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T> borsh::BorshSerialize for Cell<T>
+where
+    T: borsh::BorshSerialize + PureClone,
+{
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.get().serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T> borsh::BorshDeserialize for Cell<T>
+where
+    T: borsh::BorshDeserialize,
+{
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        T::deserialize_reader(reader).map(Cell::new)
+    }
+}
+
+#[cfg(feature = "borsh-schema")]
+impl<T> borsh::BorshSchema for Cell<T>
+where
+    T: borsh::BorshSchema,
+{
+    fn add_definitions_recursively(
+        definitions: &mut alloc::collections::BTreeMap<
+            borsh::schema::Declaration,
+            borsh::schema::Definition,
+        >,
+    ) {
+        T::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> borsh::schema::Declaration {
+        T::declaration()
+    }
+}
+
+// Only the public methods are forwarded, so unlike `Option<T>`, a `Cell<Option<T>>` field is listed
+// as required by `#[derive(JsonSchema)]`.
+#[cfg(feature = "schemars")]
+impl<T> schemars::JsonSchema for Cell<T>
+where
+    T: schemars::JsonSchema,
+{
+    fn inline_schema() -> bool {
+        T::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        T::schema_id()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        T::json_schema(generator)
+    }
+}
+
+/// Encodes a clone of the contained value, so that no reference into the `Cell` is held while the
+/// `Encode` impl of `T` runs. The encoding is the same as that of `T`.
+///
+/// `size_hint` is left at its default of `0`, because the hint of `T` could only be computed on
+/// another clone. Each encoding therefore clones the value once.
+#[cfg(feature = "scale-codec")]
+impl<T> parity_scale_codec::Encode for Cell<T>
+where
+    T: parity_scale_codec::Encode + PureClone,
+{
+    fn encode_to<O: parity_scale_codec::Output + ?Sized>(&self, dest: &mut O) {
+        self.get().encode_to(dest);
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T> parity_scale_codec::EncodeLike for Cell<T> where T: parity_scale_codec::Encode + PureClone {}
+
+#[cfg(feature = "scale-codec")]
+impl<T> parity_scale_codec::EncodeLike<T> for Cell<T> where T: parity_scale_codec::Encode + PureClone
+{}
+
+#[cfg(feature = "scale-codec")]
+impl<T> parity_scale_codec::Decode for Cell<T>
+where
+    T: parity_scale_codec::Decode,
+{
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        T::decode(input).map(Cell::new)
+    }
+
+    fn skip<I: parity_scale_codec::Input>(input: &mut I) -> Result<(), parity_scale_codec::Error> {
+        T::skip(input)
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        T::encoded_fixed_size()
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T> parity_scale_codec::DecodeWithMemTracking for Cell<T> where
+    T: parity_scale_codec::DecodeWithMemTracking
+{
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T> parity_scale_codec::MaxEncodedLen for Cell<T>
+where
+    T: parity_scale_codec::MaxEncodedLen + PureClone,
+{
+    fn max_encoded_len() -> usize {
+        T::max_encoded_len()
+    }
+}
+
+#[cfg(feature = "scale-info")]
+impl<T> scale_info::TypeInfo for Cell<T>
+where
+    T: scale_info::TypeInfo + 'static,
+{
+    type Identity = T;
+
+    fn type_info() -> scale_info::Type {
+        T::type_info()
+    }
+}
+
+// `Cell<T>` is visited like a struct named `Cell` with a single field `value`, matching its `Debug`
+// impl. It cannot take on the shape of `T` instead. `as_value` has to return a `Value` borrowing
+// from `self`, so forwarding to `T::as_value` would need a reference into the cell, and returning
+// `Value::Valuable(self)` makes visitors such as the `Debug` impl of `Value` recurse forever.
+// Likewise, `Structable` and `Enumerable` are not forwarded to `T`, because the definitions that
+// they return may borrow from the value.
+#[cfg(feature = "valuable")]
+static VALUABLE_FIELDS: &[valuable::NamedField<'static>] = &[valuable::NamedField::new("value")];
+
+#[cfg(feature = "valuable")]
+impl<T> valuable::Valuable for Cell<T>
+where
+    T: valuable::Valuable + PureClone,
+{
+    fn as_value(&self) -> valuable::Value<'_> {
+        valuable::Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn valuable::Visit) {
+        let value = self.get();
+        visit.visit_named_fields(&valuable::NamedValues::new(
+            VALUABLE_FIELDS,
+            &[value.as_value()],
+        ));
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<T> valuable::Structable for Cell<T>
+where
+    T: valuable::Valuable + PureClone,
+{
+    fn definition(&self) -> valuable::StructDef<'_> {
+        valuable::StructDef::new_static("Cell", valuable::Fields::Named(VALUABLE_FIELDS))
+    }
+}
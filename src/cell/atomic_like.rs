@@ -0,0 +1,493 @@
+//! Named wrappers over common `Cell` patterns.
+
+#[cfg(feature = "alloc")]
+use alloc::rc::{Rc, Weak};
+use core::fmt::{self, Debug, Formatter};
+
+use super::Cell;
+use crate::clone::PureClone;
+
+/// A boolean flag that can be set and cleared through a shared reference.
+///
+/// This is a thin wrapper over a `Cell<bool>` with methods named after what they do to the flag,
+/// which reads better than raw boolean cells in control logic. Despite the module name, it is not
+/// thread-safe.
+///
+/// # Examples
+///
+/// ```
+/// use clone_cell::cell::Flag;
+///
+/// let dirty = Flag::new(false);
+/// dirty.set();
+/// assert!(dirty.is_set());
+/// assert!(dirty.take());
+/// assert!(!dirty.is_set());
+/// ```
+#[derive(Clone, Default)]
+pub struct Flag(Cell<bool>);
+
+impl Flag {
+    /// Creates a new `Flag` with the given state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Flag;
+    ///
+    /// let f = Flag::new(true);
+    /// assert!(f.is_set());
+    /// ```
+    #[inline]
+    pub const fn new(value: bool) -> Self {
+        Self(Cell::new(value))
+    }
+
+    /// Sets the flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Flag;
+    ///
+    /// let f = Flag::new(false);
+    /// f.set();
+    /// assert!(f.is_set());
+    /// ```
+    #[inline]
+    pub fn set(&self) {
+        self.0.set(true);
+    }
+
+    /// Clears the flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Flag;
+    ///
+    /// let f = Flag::new(true);
+    /// f.clear();
+    /// assert!(!f.is_set());
+    /// ```
+    #[inline]
+    pub fn clear(&self) {
+        self.0.set(false);
+    }
+
+    /// Returns whether the flag is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Flag;
+    ///
+    /// let f = Flag::new(false);
+    /// assert!(!f.is_set());
+    /// ```
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        self.0.get()
+    }
+
+    /// Flips the flag, and returns its new state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Flag;
+    ///
+    /// let f = Flag::new(false);
+    /// assert!(f.toggle());
+    /// assert!(!f.toggle());
+    /// ```
+    #[inline]
+    pub fn toggle(&self) -> bool {
+        let value = !self.0.get();
+        self.0.set(value);
+        value
+    }
+
+    /// Clears the flag, and returns whether it was set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Flag;
+    ///
+    /// let f = Flag::new(true);
+    /// assert!(f.take());
+    /// assert!(!f.take());
+    /// ```
+    #[inline]
+    pub fn take(&self) -> bool {
+        self.0.replace(false)
+    }
+}
+
+impl Debug for Flag {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Flag").field(&self.is_set()).finish()
+    }
+}
+
+impl From<bool> for Flag {
+    fn from(value: bool) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A value paired with a version number that changes whenever the value is replaced.
+///
+/// This is useful to detect ABA problems: a reader can remember the version along with the value,
+/// and [`compare_exchange`](Versioned::compare_exchange) only replaces the value if it has not
+/// been replaced since, even if it was set back to an equal value in between. The version starts at
+/// `0` and wraps around on overflow. Like [`Flag`], it is not thread-safe.
+///
+/// # Examples
+///
+/// ```
+/// use clone_cell::cell::Versioned;
+///
+/// let v = Versioned::new("a");
+/// let (version, _) = v.get_versioned();
+/// v.set("b");
+/// v.set("a");
+/// assert_eq!(v.get(), "a");
+/// assert_eq!(v.compare_exchange(version, "c"), Err(2));
+/// ```
+pub struct Versioned<T> {
+    version: Cell<u64>,
+    value: Cell<T>,
+}
+
+impl<T> Versioned<T> {
+    /// Creates a new `Versioned` containing the given value, at version `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Versioned;
+    ///
+    /// let v = Versioned::new(42);
+    /// assert_eq!(v.get_version(), 0);
+    /// ```
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self {
+            version: Cell::new(0),
+            value: Cell::new(value),
+        }
+    }
+
+    /// Returns the current version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Versioned;
+    ///
+    /// let v = Versioned::new(42);
+    /// v.set(43);
+    /// assert_eq!(v.get_version(), 1);
+    /// ```
+    #[inline]
+    pub fn get_version(&self) -> u64 {
+        self.version.get()
+    }
+
+    /// Sets the contained value, and increments the version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Versioned;
+    ///
+    /// let v = Versioned::new(42);
+    /// v.set(42);
+    /// assert_eq!(v.get(), 42);
+    /// assert_eq!(v.get_version(), 1);
+    /// ```
+    #[inline]
+    pub fn set(&self, value: T) {
+        self.replace(value);
+    }
+
+    /// Replaces the contained value with `value`, increments the version, and returns the old
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Versioned;
+    ///
+    /// let v = Versioned::new(1);
+    /// assert_eq!(v.replace(2), 1);
+    /// assert_eq!(v.get_version(), 1);
+    /// ```
+    pub fn replace(&self, value: T) -> T {
+        // The version is bumped first, so that it has already changed if dropping the old value
+        // reenters.
+        self.version.set(self.version.get().wrapping_add(1));
+        self.value.replace(value)
+    }
+
+    /// Replaces the contained value with `value` if the version is still `version`.
+    ///
+    /// Returns the new version on success. Otherwise, returns the current version and drops
+    /// `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Versioned;
+    ///
+    /// let v = Versioned::new(1);
+    /// assert_eq!(v.compare_exchange(0, 2), Ok(1));
+    /// assert_eq!(v.compare_exchange(0, 3), Err(1));
+    /// assert_eq!(v.get(), 2);
+    /// ```
+    pub fn compare_exchange(&self, version: u64, value: T) -> Result<u64, u64> {
+        let current = self.get_version();
+        if current != version {
+            return Err(current);
+        }
+        self.set(value);
+        Ok(self.get_version())
+    }
+
+    /// Consumes the `Versioned`, returning the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Versioned;
+    ///
+    /// let v = Versioned::new(42);
+    /// assert_eq!(v.into_inner(), 42);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T> Versioned<T>
+where
+    T: PureClone,
+{
+    /// Returns a clone of the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::Versioned;
+    ///
+    /// let v = Versioned::new(Rc::new(42));
+    /// assert_eq!(*v.get(), 42);
+    /// ```
+    #[inline]
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    /// Returns the current version along with a clone of the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::Versioned;
+    ///
+    /// let v = Versioned::new(1);
+    /// v.set(2);
+    /// assert_eq!(v.get_versioned(), (1, 2));
+    /// ```
+    #[inline]
+    pub fn get_versioned(&self) -> (u64, T) {
+        (self.get_version(), self.get())
+    }
+}
+
+impl<T> Clone for Versioned<T>
+where
+    T: PureClone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            version: self.version.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<T> Debug for Versioned<T>
+where
+    T: Debug + PureClone,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Versioned")
+            .field("version", &self.get_version())
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+impl<T> Default for Versioned<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for Versioned<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A weak reference that can be replaced through a shared reference.
+///
+/// This is a thin wrapper over a `Cell<Weak<T>>`, which is a common way for an observer to refer
+/// back to what it observes without creating a reference cycle. It starts out empty, like
+/// [`Weak::new`].
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::cell::WeakSlot;
+///
+/// let slot = WeakSlot::new();
+/// let rc = Rc::new(42);
+/// slot.set(&rc);
+/// assert_eq!(slot.upgrade().as_deref(), Some(&42));
+/// drop(rc);
+/// assert!(!slot.is_alive());
+/// ```
+#[cfg(feature = "alloc")]
+pub struct WeakSlot<T>(Cell<Weak<T>>);
+
+#[cfg(feature = "alloc")]
+impl<T> WeakSlot<T> {
+    /// Creates a new empty `WeakSlot`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clone_cell::cell::WeakSlot;
+    ///
+    /// let slot = WeakSlot::<i32>::new();
+    /// assert!(slot.upgrade().is_none());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self(Cell::new(Weak::new()))
+    }
+
+    /// Stores a weak reference to `rc`, replacing the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::WeakSlot;
+    ///
+    /// let slot = WeakSlot::new();
+    /// let rc = Rc::new(42);
+    /// slot.set(&rc);
+    /// assert_eq!(Rc::strong_count(&rc), 1);
+    /// assert!(slot.is_alive());
+    /// ```
+    #[inline]
+    pub fn set(&self, rc: &Rc<T>) {
+        self.0.set(Rc::downgrade(rc));
+    }
+
+    /// Empties the slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::WeakSlot;
+    ///
+    /// let rc = Rc::new(42);
+    /// let slot = WeakSlot::from(&rc);
+    /// slot.clear();
+    /// assert!(!slot.is_alive());
+    /// ```
+    #[inline]
+    pub fn clear(&self) {
+        self.0.set(Weak::new());
+    }
+
+    /// Returns an `Rc` to the referenced value, or `None` if the slot is empty or the value has
+    /// been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::WeakSlot;
+    ///
+    /// let rc = Rc::new(42);
+    /// let slot = WeakSlot::from(&rc);
+    /// assert!(Rc::ptr_eq(&slot.upgrade().unwrap(), &rc));
+    /// ```
+    #[inline]
+    pub fn upgrade(&self) -> Option<Rc<T>> {
+        self.0.get().upgrade()
+    }
+
+    /// Returns whether the referenced value is still alive. This is `false` if the slot is empty.
+    ///
+    /// Unlike [`upgrade`](WeakSlot::upgrade), this does not create a new `Rc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::cell::WeakSlot;
+    ///
+    /// let rc = Rc::new(42);
+    /// let slot = WeakSlot::from(&rc);
+    /// assert!(slot.is_alive());
+    /// drop(rc);
+    /// assert!(!slot.is_alive());
+    /// ```
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.0.get().strong_count() > 0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Clone for WeakSlot<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Debug for WeakSlot<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("WeakSlot").field(&self.0.get()).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for WeakSlot<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<&Rc<T>> for WeakSlot<T> {
+    fn from(rc: &Rc<T>) -> Self {
+        Self(Cell::new(Rc::downgrade(rc)))
+    }
+}
@@ -0,0 +1,22 @@
+//! Counts the writes to `Cell`s on the current thread, for
+//! [`check_pure`](crate::clone::check_pure).
+//!
+//! Without the `test-probes` feature, [`record_write`] does nothing and compiles away.
+
+#[cfg(feature = "test-probes")]
+std::thread_local! {
+    static WRITES: core::cell::Cell<u64> = const { core::cell::Cell::new(0) };
+}
+
+/// Records a write to the content of a `Cell` through a shared reference.
+#[inline(always)]
+pub(crate) fn record_write() {
+    #[cfg(feature = "test-probes")]
+    WRITES.with(|writes| writes.set(writes.get().wrapping_add(1)));
+}
+
+/// Returns the number of writes recorded on the current thread so far.
+#[cfg(feature = "test-probes")]
+pub(crate) fn writes() -> u64 {
+    WRITES.with(core::cell::Cell::get)
+}
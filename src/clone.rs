@@ -12,16 +12,74 @@
 //! and this [Rust forum thread] for details.
 //!
 //! When this [`crate`] is built with the `"derive"` feature, the [`PureClone`](derive@PureClone)
-//! proc macro can be used to derive `PureClone` for user types.
+//! proc macro can be used to derive `PureClone` for user types. The declarative
+//! [`pure_clone!`](crate::pure_clone) macro covers the common cases without a proc macro.
+//!
+//! The [`assert_pure_clone!`](crate::assert_pure_clone) and
+//! [`assert_not_pure_clone!`](crate::assert_not_pure_clone) macros check at compile time whether
+//! types implement `PureClone`. In `const` contexts, [`is_pure_clone`] does the same for a single
+//! type.
+//!
+//! [`PureEq`] and [`PureOrd`] make the same promise for comparisons, which lets
+//! [`Cell::pure_eq`](crate::cell::Cell::pure_eq) and its siblings compare cells without cloning
+//! their contents.
+//!
+//! ## Standard library types
+//!
+//! `PureClone` is implemented for the following types from `core` and `alloc`:
+//! - Primitive types other than `str` and slices, [`FpCategory`](core::num::FpCategory),
+//!   [`Ordering`](core::cmp::Ordering), [`Duration`](core::time::Duration), the `NonZero` integer
+//!   types, the IP and socket address types from [`core::net`], and `!` with the `"never_type"`
+//!   feature.
+//! - Shared references, raw pointers, [`NonNull<T>`](core::ptr::NonNull),
+//!   [`PhantomData<T>`](core::marker::PhantomData) and function pointers, for any `T`.
+//! - `Rc<T>`, `rc::Weak<T>`, `Arc<T>` and `sync::Weak<T>`, for any `T`, since cloning them only
+//!   updates a reference count.
+//! - Arrays, tuples of up to twelve elements, `Option<T>`, `Result<T, E>`,
+//!   [`ControlFlow<B, C>`](core::ops::ControlFlow), [`Saturating<T>`](core::num::Saturating),
+//!   [`Bound<T>`](core::ops::Bound) and the range types, when their elements are `PureClone`.
+//! - `Box<T>`, `Box<[T]>`, `Vec<T>` and `BTreeSet<T>` when `T` is `PureClone`, and `Box<str>` and
+//!   `String`.
+//! - `Cow<'_, B>` when `B` is [`PureToOwned`], e.g., `Cow<'_, str>`, and `Cow<'_, [T]>` when `T` is
+//!   `PureClone`.
+//!
+//! Types whose `clone` reads shared mutable state are not `PureClone`, e.g., `RefCell<T>`,
+//! `core::cell::Cell<T>`, [`Cell<T>`](crate::cell::Cell), `Mutex<T>` and `RwLock<T>`. Neither are
+//! `&mut T`, which is not `Clone`, and boxed closures.
+//!
+//! ```
+//! use std::rc::Rc;
+//! use clone_cell::clone::is_pure_clone;
+//!
+//! const _: () = assert!(is_pure_clone::<(Rc<str>, Option<Vec<u8>>, [fn(); 2])>());
+//! ```
+//!
+//! ```compile_fail
+//! use std::cell::RefCell;
+//! use clone_cell::clone::is_pure_clone;
+//!
+//! const _: () = assert!(is_pure_clone::<Option<RefCell<i32>>>());
+//! ```
 //!
 //! [Rust forum thread]:
 //! https://users.rust-lang.org/t/why-does-cell-require-copy-instead-of-clone/5769/3
 //! [Stack Overflow answer]:
 //! https://stackoverflow.com/questions/39667868/why-can-cell-in-rust-only-be-used-for-copy-and-not-clone-types
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, boxed::Box};
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+    ops::{Deref, DerefMut},
+};
+
 /// A derive macro that generates impls of the traits [`PureClone`] and [`Clone`].
 ///
-/// See the [crate#soundness] doc on why this macro also generates a `Clone` impl.
+/// See the [crate#soundness] doc on why this macro also generates a `Clone` impl. Unlike
+/// `#[derive(Clone)]`, the generated `Clone` impl also overrides [`Clone::clone_from`] to call
+/// `clone_from` on each field when both values are the same variant, which reuses allocations such
+/// as the buffer of a `Vec` field.
 ///
 /// # Examples
 ///
@@ -60,12 +118,219 @@
 /// assert_eq!(f.get().t, Some(42));
 /// assert_eq!(f.get().x, 21);
 /// ```
+///
+/// # Deriving `Clone`
+///
+/// This macro replaces `#[derive(Clone)]`, so `Clone` must not be derived as well. A derive macro
+/// cannot see the other derives in the same attribute, so `#[derive(Clone, PureClone)]` results in
+/// conflicting `Clone` impls. To keep the standard `Clone` derive, e.g., because another macro
+/// relies on it, opt out of the generated `Clone` impl with `unsafe_trust_clone` (see below). This
+/// is sound because the standard derive only clones each field, which are all `PureClone`. Note
+/// that the standard derive requires every type parameter to be `Clone`, so a parameter that is
+/// only used in fields such as `Rc<T>` also needs `where = "T: Clone"`.
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{cell::Cell, clone::PureClone};
+///
+/// #[derive(Clone, PureClone)]
+/// #[pure_clone(unsafe_trust_clone)]
+/// struct Foo {
+///     p: Rc<i32>,
+/// }
+///
+/// let c = Cell::new(Foo { p: Rc::new(42) });
+/// assert_eq!(*c.get().p, 42);
+/// ```
+///
+/// # Generic parameters
+///
+/// The generated impls are bounded by the types of the fields that reference a generic parameter,
+/// not by the parameters themselves. So a parameter that is only used behind an `Rc` or in a
+/// `PhantomData` does not need to be `Clone` at all, even in an `enum`:
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{cell::Cell, clone::PureClone};
+///
+/// #[derive(PureClone)]
+/// enum Msg<T, U> {
+///     Data(T),
+///     Shared(Rc<U>),
+/// }
+///
+/// struct NotClone;
+///
+/// let c: Cell<Msg<i32, NotClone>> = Cell::new(Msg::Data(42));
+/// assert!(matches!(c.get(), Msg::Data(42)));
+/// ```
+///
+/// A field that stores a parameter by value is always bounded, even if the variant holding it is
+/// never constructed. `clone` has to handle every variant, so there is no way to leave out such a
+/// bound.
+///
+/// # Function pointers
+///
+/// Fields of function pointer types are supported, including higher-ranked ones such as
+/// `for<'a> fn(&'a str) -> &'a str` or `fn(&T)`. `PureClone` is only implemented for function
+/// pointers that are not higher-ranked, so these must not be nested in another type such as
+/// `Option` for the derive to work.
+///
+/// # Associated types
+///
+/// Fields whose types are associated types of a generic parameter are bounded as written. So a
+/// struct configured through a trait is `PureClone` whenever the associated types it stores are.
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{cell::Cell, clone::PureClone};
+///
+/// trait Config {
+///     type Storage;
+/// }
+///
+/// #[derive(PureClone)]
+/// struct Node<C: Config> {
+///     value: C::Storage,
+///     next: Option<Rc<Node<C>>>,
+/// }
+///
+/// struct Shared;
+///
+/// impl Config for Shared {
+///     type Storage = Rc<str>;
+/// }
+///
+/// let c: Cell<Node<Shared>> = Cell::new(Node {
+///     value: Rc::from("head"),
+///     next: None,
+/// });
+/// assert_eq!(&*c.get().value, "head");
+/// ```
+///
+/// # Packed structs
+///
+/// The fields of a `#[repr(packed)]` struct cannot be borrowed, so they are copied out before
+/// being cloned. This requires every field to be `Copy`.
+///
+/// # Attributes
+///
+/// The following container attributes can be given with `#[pure_clone(...)]`:
+///
+/// - `copy`: Also implements [`Copy`], and implements `clone` as `*self`. This fails to compile if
+///   any field is not `Copy`.
+/// - `crate = "path"`: Uses `path` instead of `::clone_cell` to refer to this crate in the
+///   generated code. This is useful when this crate is re-exported under a different name.
+/// - `trusted`: Also implements [`TrustedPureClone`]. This fails to compile if any field is not
+///   `TrustedPureClone`. This cannot be combined with `unsafe_trust_clone`.
+/// - `unsafe_trust_clone`: Does not generate a `Clone` impl, and uses the existing one instead.
+///   Every field must still be `PureClone`, but the body of the hand-written `clone` method is not
+///   checked in any way. It is the user's obligation to ensure that it upholds the safety
+///   requirements of [`PureClone`]: it must not access the content of any
+///   [`Cell`](crate::cell::Cell), and it must only clone the fields through their own `Clone`
+///   impls. This cannot be combined with `copy`.
+/// - `where = "predicates"`: Appends the given comma-separated `where` predicates to the generated
+///   impls, on top of the inferred bounds. This is useful when a field goes through an associated
+///   type, or when a hand-written `Clone` impl needs more bounds than the fields do.
+///
+/// ```
+/// use clone_cell::{cell::Cell, clone::PureClone};
+///
+/// #[derive(PureClone)]
+/// #[pure_clone(copy)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let p = Point { x: 1, y: 2 };
+/// let c = Cell::new(p);
+/// assert_eq!(c.get().x, p.x);
+/// ```
+///
+/// ```
+/// use clone_cell::{cell::Cell, clone::PureClone};
+///
+/// #[derive(PureClone)]
+/// #[pure_clone(unsafe_trust_clone)]
+/// struct Tracked {
+///     value: i32,
+///     generation: u32,
+/// }
+///
+/// // This `clone` only reads the fields of `self`, so it is pure.
+/// impl Clone for Tracked {
+///     fn clone(&self) -> Self {
+///         Self {
+///             value: self.value,
+///             generation: self.generation + 1,
+///         }
+///     }
+/// }
+///
+/// let c = Cell::new(Tracked {
+///     value: 42,
+///     generation: 0,
+/// });
+/// assert_eq!(c.get().generation, 1);
+/// ```
 #[cfg(feature = "derive")]
 pub use crate::derive::PureClone;
 
+/// An attribute macro for a hand-written `impl Clone` block that also implements [`PureClone`]
+/// with the same generics and `where` clause.
+///
+/// This is an alternative to a separate `unsafe impl PureClone` for types whose `Clone` impl cannot
+/// be derived. It keeps the promise next to the code it is about, and the `PureClone` impl in sync
+/// with the generics of the `Clone` impl. The body of the `Clone` impl is **not checked**: whether
+/// it mutates a cell cannot be decided from its tokens, since a cell can be reached through a
+/// helper function, a macro or a function pointer, and methods that write to a cell share their
+/// names with unrelated ones, such as `Option::take`. So the attribute must be written as
+/// `#[pure_clone_impl(unsafe)]`, and the safety requirements of [`PureClone`] have to be upheld by
+/// the user.
+///
+/// `#[pure_clone_impl(unsafe, crate = "path")]` can be used to refer to this crate as `path`, as
+/// with the derive macro.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{cell::Cell, clone::pure_clone_impl};
+///
+/// struct Counter {
+///     shared: Rc<Cell<u32>>,
+///     id: u32,
+/// }
+///
+/// // SAFETY: `clone` only clones the `Rc` and copies the `id`.
+/// #[pure_clone_impl(unsafe)]
+/// impl Clone for Counter {
+///     fn clone(&self) -> Self {
+///         Self {
+///             shared: self.shared.clone(),
+///             id: self.id + 1,
+///         }
+///     }
+/// }
+///
+/// let c = Cell::new(Counter {
+///     shared: Rc::new(Cell::new(0)),
+///     id: 0,
+/// });
+/// assert_eq!(c.get().id, 1);
+/// ```
+#[cfg(feature = "derive")]
+pub use crate::derive::pure_clone_impl;
+
 /// The `PureClone` trait, which is a subtrait of [`Clone`].
 ///
 /// See the [module](self) documentation for more information.
+///
+/// # Safety
+///
+/// Implementations must ensure that `clone` (and therefore `pure_clone`) never mutates the content
+/// of any [`Cell`](crate::cell::Cell) that may contain `self`.
 pub unsafe trait PureClone: Clone {
     /// The `pure_clone` method.
     #[inline]
@@ -74,29 +339,467 @@ pub unsafe trait PureClone: Clone {
     }
 }
 
+/// A [`PureClone`] type whose `clone` also never panics.
+///
+/// A `clone` that panics midway is a hazard for code that must not be interrupted between two
+/// steps, such as an update that would be left torn. Such code can require this trait instead of
+/// `PureClone`.
+///
+/// This is implemented for the primitive types, references, raw and function pointers, `Rc`,
+/// `Arc`, their `Weak` pointers, and for `Option`, `Result`, arrays, tuples and ranges of such
+/// types. The [`PureClone`](derive@PureClone) derive macro implements it with
+/// `#[pure_clone(trusted)]`.
+///
+/// # Safety
+///
+/// In addition to the requirements of [`PureClone`], implementations must ensure that `clone`
+/// (and therefore `pure_clone`) never panics. Aborting the process is not a panic, so a `clone`
+/// that can only fail by aborting qualifies, such as those of `Rc` and `Arc`, which abort when
+/// their reference counts overflow. A failed allocation aborts by default too, but it can be
+/// configured to panic instead, so types whose `clone` allocates (such as `Box` and `Vec`) are not
+/// `TrustedPureClone`.
+pub unsafe trait TrustedPureClone: PureClone {}
+
+/// A [`ToOwned`] type whose `to_owned` does not mutate the content of any
+/// [`Cell`](crate::cell::Cell).
+///
+/// This is the counterpart of [`PureClone`] for borrowed types such as `str` and `[T]`. It is
+/// implemented for `str`, [`CStr`](core::ffi::CStr), `[T]` where `T` is `PureClone`, and every
+/// `PureClone` type. `Cow<'_, B>` is `PureClone` when `B` is `PureToOwned`.
+///
+/// # Safety
+///
+/// Implementations must ensure that `to_owned` never mutates the content of any `Cell` that may
+/// contain `self`. The same applies to the [`Borrow`](core::borrow::Borrow) impl of `Self::Owned`,
+/// which `Cow` calls when cloning an owned value.
+#[cfg(feature = "alloc")]
+pub unsafe trait PureToOwned: ToOwned {}
+
+/// A [`PartialEq`] type whose `eq` does not mutate the content of any
+/// [`Cell`](crate::cell::Cell).
+///
+/// [`Cell::pure_eq`](crate::cell::Cell::pure_eq) compares the contents of two cells in place for
+/// such types, instead of cloning both of them as the `PartialEq` impl of `Cell` does.
+///
+/// This is implemented for the primitive types, `str`, the `NonZero` integer types, `Ordering`,
+/// `Duration`, and for shared references, slices, arrays, tuples, `Option`, `Result`, `Box`, `Rc`,
+/// `Arc`, `Vec` and `String` of such types.
+///
+/// # Safety
+///
+/// Implementations must ensure that `eq` and `ne` never mutate the content of any `Cell` that may
+/// contain `self` or `other`.
+pub unsafe trait PureEq: PartialEq {}
+
+/// A [`PartialOrd`] type whose comparisons do not mutate the content of any
+/// [`Cell`](crate::cell::Cell).
+///
+/// [`Cell::pure_partial_cmp`](crate::cell::Cell::pure_partial_cmp) and
+/// [`Cell::pure_cmp`](crate::cell::Cell::pure_cmp) compare the contents of two cells in place for
+/// such types. This is implemented for the same types as [`PureEq`].
+///
+/// # Safety
+///
+/// Implementations must ensure that the methods of `PartialOrd`, and of [`Ord`] if the type
+/// implements it, never mutate the content of any `Cell` that may contain `self` or `other`.
+pub unsafe trait PureOrd: PartialOrd + PureEq {}
+
+/// Returns `true`, and fails to compile if `T` is not [`PureClone`].
+///
+/// Like [`assert_pure_clone!`](crate::assert_pure_clone), this checks at compile time whether a
+/// type is `PureClone`, but it can be used in expressions, including `const` ones.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use clone_cell::clone::is_pure_clone;
+///
+/// const ARC_IS_PURE_CLONE: bool = is_pure_clone::<Arc<str>>();
+/// assert!(ARC_IS_PURE_CLONE);
+/// ```
+#[inline]
+pub const fn is_pure_clone<T>() -> bool
+where
+    T: PureClone,
+{
+    true
+}
+
+/// An extension trait for iterators over references to [`PureClone`] values.
+///
+/// [`pure_cloned`](IteratorPureCloneExt::pure_cloned) works like [`Iterator::cloned`], but only
+/// for `PureClone` elements, so that generic code can keep `PureClone` bounds in its signatures
+/// instead of `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::clone::{IteratorPureCloneExt, PureClone};
+///
+/// fn snapshot<T: PureClone>(items: &[T]) -> Vec<T> {
+///     items.iter().pure_cloned().collect()
+/// }
+///
+/// let v = [Rc::new(1), Rc::new(2)];
+/// let s = snapshot(&v);
+/// assert_eq!(Rc::strong_count(&v[0]), 2);
+/// assert_eq!(*s[1], 2);
+/// ```
+pub trait IteratorPureCloneExt: Iterator {
+    /// Creates an iterator that clones each of the referenced elements with
+    /// [`pure_clone`](PureClone::pure_clone).
+    #[inline]
+    fn pure_cloned<'a, T>(self) -> PureCloned<Self>
+    where
+        Self: Iterator<Item = &'a T> + Sized,
+        T: PureClone + 'a,
+    {
+        PureCloned { iter: self }
+    }
+}
+
+impl<I> IteratorPureCloneExt for I where I: Iterator {}
+
+/// An iterator that clones the elements of an underlying iterator with
+/// [`pure_clone`](PureClone::pure_clone).
+///
+/// This is created by [`IteratorPureCloneExt::pure_cloned`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PureCloned<I> {
+    iter: I,
+}
+
+impl<'a, I, T> Iterator for PureCloned<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: PureClone + 'a,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(PureClone::pure_clone)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I, T> DoubleEndedIterator for PureCloned<I>
+where
+    I: DoubleEndedIterator<Item = &'a T>,
+    T: PureClone + 'a,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(PureClone::pure_clone)
+    }
+}
+
+impl<'a, I, T> ExactSizeIterator for PureCloned<I>
+where
+    I: ExactSizeIterator<Item = &'a T>,
+    T: PureClone + 'a,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, I, T> FusedIterator for PureCloned<I>
+where
+    I: FusedIterator<Item = &'a T>,
+    T: PureClone + 'a,
+{
+}
+
+/// An object-safe counterpart of [`PureClone`].
+///
+/// `PureClone` cannot be used as a trait object because `pure_clone` returns `Self`. This trait is
+/// implemented for every `'static` `PureClone` type instead, and `Box<dyn DynPureClone>` is
+/// `PureClone`.
+///
+/// It can also be used as a supertrait of another trait, so that boxed trait objects of that trait
+/// can be cloned too. See [`pure_clone_trait_object!`](crate::pure_clone_trait_object).
+///
+/// # Safety
+///
+/// The same requirements as for [`PureClone`] apply to `pure_clone_box`. In addition, the returned
+/// `Box` must contain a value of the same type as `self`.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{cell::Cell, clone::DynPureClone};
+///
+/// let p = Rc::new(42);
+/// let c: Cell<Box<dyn DynPureClone>> = Cell::new(Box::new(p.clone()));
+/// let b = c.get();
+/// assert_eq!(Rc::strong_count(&p), 3);
+/// ```
+#[cfg(feature = "alloc")]
+pub unsafe trait DynPureClone {
+    /// Clones `self` into a new `Box`.
+    fn pure_clone_box(&self) -> Box<dyn DynPureClone>;
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T> DynPureClone for T
+where
+    T: PureClone + 'static,
+{
+    fn pure_clone_box(&self) -> Box<dyn DynPureClone> {
+        Box::new(self.pure_clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Clone for Box<dyn DynPureClone> {
+    fn clone(&self) -> Self {
+        (**self).pure_clone_box()
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl PureClone for Box<dyn DynPureClone> {}
+
+/// A wrapper that makes any `Clone` type `PureClone`, with the caller vouching for its `clone`.
+///
+/// This is useful for a foreign type whose `clone` has been audited, or which is `Copy` but does not
+/// implement `PureClone`, without having to define a newtype for it.
+///
+/// A `Pure` holds the function it clones its value with: [`Clone::clone`] if it was created with
+/// [`new`](Pure::new), and a bitwise copy if it was created with [`new_copy`](Pure::new_copy).
+/// `new_copy` is safe because a `Copy` type can still have a `Clone` impl that is not pure, which a
+/// bitwise copy never calls.
+///
+/// # Examples
+///
+/// ```
+/// use clone_cell::{cell::Cell, clone::Pure};
+///
+/// // Stands in for a foreign `Copy` type that does not implement `PureClone`.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Rgb(u8, u8, u8);
+///
+/// let c = Cell::new(Pure::new_copy(Rgb(0, 0, 0)));
+/// c.set(Pure::new_copy(Rgb(255, 0, 0)));
+/// assert_eq!(*c.get(), Rgb(255, 0, 0));
+/// assert_eq!(c.get().into_inner().0, 255);
+/// ```
+pub struct Pure<T> {
+    value: T,
+    clone: fn(&T) -> T,
+}
+
+impl<T> Pure<T> {
+    /// Wraps `value`, whose `clone` the caller asserts to be pure.
+    ///
+    /// # Safety
+    ///
+    /// The `clone` method of `T` must uphold the requirements of [`PureClone`] for every value that
+    /// is cloned through this `Pure`, including those stored later through [`DerefMut`]: it must not
+    /// mutate the content of any [`Cell`](crate::cell::Cell) that may contain the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use clone_cell::{cell::Cell, clone::Pure};
+    ///
+    /// // Stands in for a foreign type.
+    /// #[derive(Clone)]
+    /// struct Handle {
+    ///     id: u32,
+    ///     shared: Rc<str>,
+    /// }
+    ///
+    /// // SAFETY: `Handle::clone` only clones its fields, a `u32` and an `Rc`, neither of which can
+    /// // reach a `Cell`.
+    /// let h = unsafe {
+    ///     Pure::new(Handle {
+    ///         id: 1,
+    ///         shared: Rc::from("handle"),
+    ///     })
+    /// };
+    /// let c = Cell::new(h);
+    /// assert_eq!(c.get().id, 1);
+    /// ```
+    #[inline]
+    pub const unsafe fn new(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            value,
+            clone: T::clone,
+        }
+    }
+
+    /// Wraps `value`, which is cloned with a bitwise copy instead of its `clone` method.
+    #[inline]
+    pub const fn new_copy(value: T) -> Self
+    where
+        T: Copy,
+    {
+        Self { value, clone: copy }
+    }
+
+    /// Unwraps the value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+fn copy<T>(value: &T) -> T
+where
+    T: Copy,
+{
+    *value
+}
+
+impl<T> Clone for Pure<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            value: (self.clone)(&self.value),
+            clone: self.clone,
+        }
+    }
+}
+
+unsafe impl<T> PureClone for Pure<T> {}
+
+impl<T> Debug for Pure<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Pure").field(&self.value).finish()
+    }
+}
+
+impl<T> Deref for Pure<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Pure<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+/// Clones `value` and panics if the clone wrote to a [`Cell`](crate::cell::Cell) on the current
+/// thread. Returns the clone.
+///
+/// This is meant for tests of a type before it is declared `PureClone` with an `unsafe impl`. It is
+/// only a best-effort check: it sees the writes made by the `clone` calls it runs, but not those a
+/// different input could trigger, and writes through [`as_ptr`](crate::cell::Cell::as_ptr) or to
+/// other kinds of cells (such as [`core::cell::Cell`]) are not counted.
+///
+/// This requires the `test-probes` feature.
+///
+/// # Panics
+///
+/// Panics if `clone` wrote to a `Cell`.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{cell::Cell, clone::check_pure};
+///
+/// #[derive(Clone)]
+/// struct Foo {
+///     ptr: Rc<Cell<i32>>,
+/// }
+///
+/// let foo = check_pure(Foo {
+///     ptr: Rc::new(Cell::new(42)),
+/// });
+/// assert_eq!(foo.ptr.get(), 42);
+/// ```
+#[cfg(feature = "test-probes")]
+#[track_caller]
+pub fn check_pure<T>(value: T) -> T
+where
+    T: Clone,
+{
+    use crate::cell::{probe, Cell};
+
+    let guard = Cell::new(value);
+    let writes = probe::writes();
+    // SAFETY: `guard` is not reachable from anywhere else, so `clone` cannot mutate it.
+    let clone = unsafe { guard.with(T::clone) };
+    if probe::writes() != writes {
+        panic!("`clone` wrote to a `Cell`, so it is not pure");
+    }
+    clone
+}
+
 /// Implementations for types that are known to have compliant `clone` implementations.
 mod impls {
+    #[cfg(feature = "alloc")]
     use alloc::{
+        borrow::Cow,
         boxed::Box,
+        collections::BTreeSet,
         rc::{Rc, Weak},
+        string::String,
         sync::{Arc, Weak as SyncWeak},
         vec::Vec,
     };
+    #[cfg(feature = "alloc")]
+    use core::ffi::CStr;
+    use core::{
+        cmp::Ordering,
+        marker::PhantomData,
+        net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+        num::{
+            FpCategory, NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize,
+            NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating,
+        },
+        ops::{
+            Bound, ControlFlow, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+            RangeToInclusive,
+        },
+        ptr::NonNull,
+        time::Duration,
+    };
 
-    use super::PureClone;
+    #[cfg(feature = "alloc")]
+    use super::PureToOwned;
+    use super::{PureClone, PureEq, PureOrd, TrustedPureClone};
 
     macro_rules! impl_pure_clone {
         ($($t:ty)*) => {
             $(
                 unsafe impl PureClone for $t {}
+                unsafe impl TrustedPureClone for $t {}
             )*
         }
     }
 
+    #[cfg(feature = "alloc")]
     macro_rules! impl_pure_clone_rc {
         ($($i:ident<T>)*) => {
             $(
                 unsafe impl<T> PureClone for $i<T> where T: ?Sized {}
+                unsafe impl<T> TrustedPureClone for $i<T> where T: ?Sized {}
             )*
         }
     }
@@ -104,7 +807,21 @@ mod impls {
     macro_rules! impl_pure_clone_generic {
         ($($i:ident<$($j:ident),*>)*) => {
             $(
-                unsafe impl<$($j),*> PureClone for $i<$($j),*> where $($j: PureClone),* {}
+                crate::pure_clone_remote! {
+                    unsafe impl<$($j),*> PureClone for $i<$($j),*> where $($j: PureClone),*;
+                }
+            )*
+        }
+    }
+
+    macro_rules! impl_trusted_pure_clone_generic {
+        ($($i:ident<$($j:ident),*>)*) => {
+            $(
+                unsafe impl<$($j),*> TrustedPureClone for $i<$($j),*>
+                where
+                    $($j: TrustedPureClone),*
+                {
+                }
             )*
         }
     }
@@ -113,31 +830,164 @@ mod impls {
         ($(($($i:ident),*))*) => {
             $(
                 unsafe impl<$($i),*> PureClone for ($($i,)*) where $($i: PureClone),* {}
+                unsafe impl<$($i),*> TrustedPureClone for ($($i,)*) where $($i: TrustedPureClone),* {}
+            )*
+        }
+    }
+
+    macro_rules! impl_pure_cmp {
+        ($($t:ty)*) => {
+            $(
+                unsafe impl PureEq for $t {}
+                unsafe impl PureOrd for $t {}
+            )*
+        }
+    }
+
+    // The comparisons of these types only compare their elements.
+    macro_rules! impl_pure_cmp_generic {
+        ($([$($g:tt)*] $t:ty, $($j:ident)*;)*) => {
+            $(
+                unsafe impl<$($g)*> PureEq for $t where $($j: PureEq),* {}
+                unsafe impl<$($g)*> PureOrd for $t where $($j: PureOrd),* {}
+            )*
+        }
+    }
+
+    macro_rules! impl_pure_cmp_tuples {
+        ($(($($i:ident),*))*) => {
+            $(
+                unsafe impl<$($i),*> PureEq for ($($i,)*) where $($i: PureEq),* {}
+                unsafe impl<$($i),*> PureOrd for ($($i,)*) where $($i: PureOrd),* {}
+            )*
+        }
+    }
+
+    // Function pointers are cloned by copying the pointer. Only function pointers that are not
+    // higher-ranked are covered, e.g., `fn(&str)` is not, because it is `for<'a> fn(&'a str)`.
+    macro_rules! impl_pure_clone_fns {
+        ($(($($i:ident),*))*) => {
+            $(
+                unsafe impl<R, $($i),*> PureClone for fn($($i),*) -> R {}
+                unsafe impl<R, $($i),*> PureClone for unsafe fn($($i),*) -> R {}
+                unsafe impl<R, $($i),*> PureClone for extern "C" fn($($i),*) -> R {}
+                unsafe impl<R, $($i),*> PureClone for unsafe extern "C" fn($($i),*) -> R {}
+                unsafe impl<R, $($i),*> TrustedPureClone for fn($($i),*) -> R {}
+                unsafe impl<R, $($i),*> TrustedPureClone for unsafe fn($($i),*) -> R {}
+                unsafe impl<R, $($i),*> TrustedPureClone for extern "C" fn($($i),*) -> R {}
+                unsafe impl<R, $($i),*> TrustedPureClone for unsafe extern "C" fn($($i),*) -> R {}
             )*
         }
     }
 
-    unsafe impl<T> PureClone for &T where T: ?Sized {}
+    crate::pure_clone_remote! {
+        unsafe impl<T> PureClone for &T where T: ?Sized;
+        unsafe impl<T> PureClone for *const T where T: ?Sized;
+        unsafe impl<T> PureClone for *mut T where T: ?Sized;
+        unsafe impl<T> PureClone for NonNull<T> where T: ?Sized;
+        unsafe impl<T> PureClone for PhantomData<T> where T: ?Sized;
+        unsafe impl<T, const N: usize> PureClone for [T; N] where T: PureClone;
+    }
+
+    unsafe impl<T> TrustedPureClone for &T where T: ?Sized {}
+
+    unsafe impl<T> TrustedPureClone for *const T where T: ?Sized {}
+
+    unsafe impl<T> TrustedPureClone for *mut T where T: ?Sized {}
+
+    unsafe impl<T> TrustedPureClone for NonNull<T> where T: ?Sized {}
+
+    unsafe impl<T> TrustedPureClone for PhantomData<T> where T: ?Sized {}
+
+    unsafe impl<T, const N: usize> TrustedPureClone for [T; N] where T: TrustedPureClone {}
+
+    #[cfg(feature = "never_type")]
+    unsafe impl PureClone for ! {}
+
+    #[cfg(feature = "never_type")]
+    unsafe impl TrustedPureClone for ! {}
 
     impl_pure_clone! {
         usize u8 u16 u32 u64 u128
         isize i8 i16 i32 i64 i128
-        f32 f64
-        bool char
+        f32 f64 FpCategory
+        bool char Ordering Duration
+        RangeFull
+        IpAddr Ipv4Addr Ipv6Addr SocketAddr SocketAddrV4 SocketAddrV6
+        NonZeroUsize NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128
+        NonZeroIsize NonZeroI8 NonZeroI16 NonZeroI32 NonZeroI64 NonZeroI128
     }
 
+    #[cfg(feature = "alloc")]
     impl_pure_clone_rc! {
         Rc<T> Weak<T>
         Arc<T> SyncWeak<T>
     }
 
     impl_pure_clone_generic! {
-        Box<T>
         Option<T>
         Result<T, E>
+        ControlFlow<B, C>
+        Saturating<T>
+        Bound<T>
+        Range<Idx>
+        RangeFrom<Idx>
+        RangeInclusive<Idx>
+        RangeTo<Idx>
+        RangeToInclusive<Idx>
+    }
+
+    impl_trusted_pure_clone_generic! {
+        Option<T>
+        Result<T, E>
+        ControlFlow<B, C>
+        Saturating<T>
+        Bound<T>
+        Range<Idx>
+        RangeFrom<Idx>
+        RangeInclusive<Idx>
+        RangeTo<Idx>
+        RangeToInclusive<Idx>
+    }
+
+    #[cfg(feature = "alloc")]
+    impl_pure_clone_generic! {
+        Box<T>
+        BTreeSet<T>
         Vec<T>
     }
 
+    #[cfg(feature = "alloc")]
+    unsafe impl<T> PureClone for Box<[T]> where T: PureClone {}
+
+    #[cfg(feature = "alloc")]
+    unsafe impl PureClone for Box<str> {}
+
+    // `String::clone` only copies bytes.
+    #[cfg(feature = "alloc")]
+    unsafe impl PureClone for String {}
+
+    // `to_owned` only copies bytes for these types, and clones each element for slices.
+    #[cfg(feature = "alloc")]
+    unsafe impl PureToOwned for str {}
+
+    #[cfg(feature = "alloc")]
+    unsafe impl PureToOwned for CStr {}
+
+    #[cfg(feature = "alloc")]
+    unsafe impl<T> PureToOwned for [T] where T: PureClone {}
+
+    // `ToOwned` is implemented with `clone` for every `Clone` type, and `Borrow<T>` for `T` is the
+    // identity.
+    #[cfg(feature = "alloc")]
+    unsafe impl<T> PureToOwned for T where T: PureClone {}
+
+    // Cloning a `Cow` either copies the reference or calls `to_owned` on the borrowed owned value.
+    #[cfg(feature = "alloc")]
+    crate::pure_clone_remote! {
+        unsafe impl<'a, B: PureToOwned + ?Sized> PureClone for Cow<'a, B>;
+    }
+
     impl_pure_clone_tuples! {
         ()
         (A)
@@ -153,4 +1003,67 @@ mod impls {
         (A, B, C, D, E, F, G, H, I, J, K)
         (A, B, C, D, E, F, G, H, I, J, K, L)
     }
+
+    impl_pure_cmp! {
+        usize u8 u16 u32 u64 u128
+        isize i8 i16 i32 i64 i128
+        f32 f64
+        bool char str Ordering Duration
+        IpAddr Ipv4Addr Ipv6Addr SocketAddr SocketAddrV4 SocketAddrV6
+        NonZeroUsize NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128
+        NonZeroIsize NonZeroI8 NonZeroI16 NonZeroI32 NonZeroI64 NonZeroI128
+    }
+
+    #[cfg(feature = "alloc")]
+    impl_pure_cmp! {
+        String
+    }
+
+    impl_pure_cmp_generic! {
+        [T: ?Sized] &T, T;
+        [T] [T], T;
+        [T, const N: usize] [T; N], T;
+        [T] Option<T>, T;
+        [T, E] Result<T, E>, T E;
+    }
+
+    #[cfg(feature = "alloc")]
+    impl_pure_cmp_generic! {
+        [T: ?Sized] Box<T>, T;
+        [T: ?Sized] Rc<T>, T;
+        [T: ?Sized] Arc<T>, T;
+        [T] Vec<T>, T;
+    }
+
+    impl_pure_cmp_tuples! {
+        ()
+        (A)
+        (A, B)
+        (A, B, C)
+        (A, B, C, D)
+        (A, B, C, D, E)
+        (A, B, C, D, E, F)
+        (A, B, C, D, E, F, G)
+        (A, B, C, D, E, F, G, H)
+        (A, B, C, D, E, F, G, H, I)
+        (A, B, C, D, E, F, G, H, I, J)
+        (A, B, C, D, E, F, G, H, I, J, K)
+        (A, B, C, D, E, F, G, H, I, J, K, L)
+    }
+
+    impl_pure_clone_fns! {
+        ()
+        (A)
+        (A, B)
+        (A, B, C)
+        (A, B, C, D)
+        (A, B, C, D, E)
+        (A, B, C, D, E, F)
+        (A, B, C, D, E, F, G)
+        (A, B, C, D, E, F, G, H)
+        (A, B, C, D, E, F, G, H, I)
+        (A, B, C, D, E, F, G, H, I, J)
+        (A, B, C, D, E, F, G, H, I, J, K)
+        (A, B, C, D, E, F, G, H, I, J, K, L)
+    }
 }
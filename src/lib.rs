@@ -22,26 +22,68 @@
 //! See the documentation for [`PureClone`] for a list of implemented types and the [`clone`] module
 //! documentation for more details.
 //!
+//! # Crate features
+//!
+//! - `alloc` (enabled by default): Implements `PureClone` for types from the [`alloc`] crate, such
+//!   as `Box`, `Rc` and `Vec`, and provides the `Cell` methods and other items that use them.
+//!   Without it, this crate only depends on [`core`].
+//! - `derive` (enabled by default): Provides the [`PureClone`](derive@clone::PureClone) derive
+//!   macro and the [`pure_clone_impl`](clone::pure_clone_impl) attribute macro.
+//! - `test-probes`: Provides `clone::check_pure`, which checks at run time that a `clone` does not
+//!   write to any `Cell`. This links `std`, and adds a thread-local counter that every write to a
+//!   `Cell` through a shared reference increments.
+//! - `serde`: Implements `Serialize` and `Deserialize` for `Cell<T>`, with the same format as `T`.
+//! - `arbitrary`: Implements `Arbitrary` for `Cell<T>`, by generating a `T`. This links `std`.
+//! - `proptest`: Implements proptest's `Arbitrary` for `Cell<T>`, and provides
+//!   `cell::cell_strategy` to wrap a custom strategy. This links `std`.
+//! - `quickcheck`: Implements quickcheck's `Arbitrary` for `Cell<T>`. This enables `alloc` and
+//!   links `std`.
+//! - `bytemuck`: Implements `Zeroable` for `Cell<T>`.
+//! - `defmt`: Implements `defmt::Format` for `Cell<T>`, formatting it like `Debug` does.
+//! - `rkyv`: Implements rkyv's `Archive`, `Serialize` and `Deserialize` for `Cell<T>`. The archived
+//!   form, `cell::ArchivedCell`, has the same layout as the archived `T`.
+//! - `borsh`: Implements `BorshSerialize` and `BorshDeserialize` for `Cell<T>`, with the same
+//!   encoding as `T`.
+//! - `borsh-schema`: Also implements `BorshSchema` for `Cell<T>`, with the same schema as `T`. This
+//!   enables `alloc` and borsh's `unstable__schema` feature.
+//! - `schemars`: Implements `JsonSchema` for `Cell<T>`, with the same schema as `T`. This enables
+//!   `alloc`.
+//! - `zerocopy`: Implements zerocopy's `FromBytes`, `FromZeros`, `TryFromBytes`, `IntoBytes`,
+//!   `KnownLayout` and `Unaligned` for `Cell<T>` when `T` implements them. `Immutable` is not
+//!   implemented, so a `Cell<T>` can only be cast from and into `&mut [u8]`.
+//! - `scale-codec`: Implements parity-scale-codec's `Encode`, `Decode` and `MaxEncodedLen` for
+//!   `Cell<T>`, with the same encoding as `T`.
+//! - `scale-info`: Implements `TypeInfo` for `Cell<T>`, with the same type information as `T`.
+//! - `valuable`: Implements `Valuable` and `Structable` for `Cell<T>`, visiting it as a struct
+//!   `Cell { value }`, the same shape that `Debug` uses.
+//! - `never_type`: Implements `PureClone` for the never type `!`. This requires a nightly compiler.
+//!
 //! # Soundness
 //!
 //! I believe this is sound, because `PureClone` is unsafe to implement. In user code, the only ways
 //! to use `PureClone` without `unsafe` are:
 //! 1. Use types that already implement `PureClone`.
 //! 1. Use the provided [`PureClone`](derive@clone::PureClone) proc macro to derive this trait for
-//! user types, which ensures:
+//!    user types, which ensures:
 //!     - Each field/variant of a given user `struct`/`enum` is also `PureClone`.
 //!     - A `clone` method that does not call any `Cell` content accessors is implemented (such as
-//!     one generated by `#[derive(Clone)]`).
+//!       one generated by `#[derive(Clone)]`).
 //!
 //! ## Interaction with specialization
 //!
 //! The [`PureClone`](derive@clone::PureClone) proc macro generates:
 //! 1. A non-`default` `Clone` impl with trait bounds that ensure any fields with generic parameters
-//! are also `Clone`; and
+//!    are also `Clone`; and
 //! 1. A `PureClone` impl that only compiles if all fields are also `PureClone`.
 //!
 //! Item 1 is non-`default` and hence cannot be further specialized.
 //!
+//! Only the types of fields that reference a generic parameter are added as bounds to these impls.
+//! Fields with concrete types (which may well be private to the user's module) are left out of the
+//! `where` clauses, but they are still checked: the `Clone` impl clones them, and item 2 asserts
+//! that they are `PureClone`. Since these types are concrete, both checks happen when the impls are
+//! compiled, independent of how the impls are later used.
+//!
 //! The user may attempt to provide a `default` `Clone` impl (or one with a `default` `clone`
 //! method). But this is fine, because item 2 ensures every field is `PureClone` (and therefore
 //! `Clone`), so item 1 is always selected even if a user-provided `default` `Clone` impl with fewer
@@ -49,16 +91,46 @@
 //!
 //! So I think even with [RFC1210] fully implemented, this is still sound.
 //!
+//! ### No blanket impl for `Copy` types
+//!
+//! Although `Copy` types are conceptually `PureClone`, this crate does not provide
+//! `unsafe impl<T: Copy> PureClone for T`, not even on nightly as a `default` impl. Specialization
+//! only allows an impl to override a blanket impl if it applies to a subset of its types. An impl
+//! such as `PureClone for Option<T> where T: PureClone` also applies to types that are not `Copy`
+//! (e.g., `Option<Rc<i32>>`), so it would conflict with the blanket impl instead. The same goes for
+//! the impls of `Rc`, `Vec`, tuples and arrays, and for the impls generated by the derive macro for
+//! generic types. Use [`#[pure_clone(copy)]`](derive@clone::PureClone#attributes) for user types
+//! instead.
+//!
+//! ## No safe scoped reads
+//!
+//! [`Cell::with`](cell::Cell::with) lends `&T` to a closure instead of cloning, and is `unsafe`.
+//! It cannot be made safe by restricting `f` to function pointers or to closures that capture
+//! nothing, because `f` can still reach the `Cell` through the reference it is given. For example,
+//! if `T` is `Option<Rc<Node>>` and the `Node` holds the `Cell` itself, `f` can follow the `Rc` and
+//! call `set(None)`, which drops the value `f` is still borrowing. A `Cell` stored in a thread local
+//! can be reached in the same way. Ruling this out would require the same guarantee as `PureClone`
+//! for `f`, which the compiler cannot check, so the caller has to provide it with `unsafe`.
+//! Nor can a safe method with the same requirement merely documented, such as an `inspect`: safe
+//! code that ignores the documentation must not be able to cause undefined behavior.
+//!
 //! [`PureClone`]: clone::PureClone
 //! [example]:
 //! https://users.rust-lang.org/t/why-does-cell-require-copy-instead-of-clone/5769/3
 //! [RFC1210]: https://github.com/rust-lang/rfcs/blob/master/text/1210-impl-specialization.md
 
 #![no_std]
+#![cfg_attr(feature = "never_type", feature(never_type))]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "test-probes")]
+extern crate std;
 
+#[doc(hidden)]
+pub mod __private;
 pub mod cell;
 pub mod clone;
+mod macros;
 #[cfg(feature = "derive")]
 use clone_cell_derive as derive;
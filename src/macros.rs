@@ -0,0 +1,585 @@
+//! Declarative macros exported by this crate.
+
+/// Declares `PureClone` impls for types defined in the calling crate.
+///
+/// Each entry has the shape of an `unsafe impl` without a body, ending with a `;`:
+/// ```text
+/// unsafe impl<Generics> PureClone for Type where Predicates;
+/// ```
+///
+/// The generics and the `where` clause are optional. As with any `PureClone` impl, the type must
+/// also be `Clone` under the same bounds, which is checked at compile time.
+///
+/// This macro is intended for library authors who want to opt their own types into `PureClone`
+/// without depending on the `derive` feature, e.g., because the `Clone` impl is hand-written. It
+/// does **not** get around the orphan rules: a crate can only use it for types it defines itself.
+/// To store a type from another crate that is not `PureClone` in a [`Cell`](crate::cell::Cell),
+/// wrap it in a local newtype instead.
+///
+/// # Safety
+///
+/// Although the macro can be called without an `unsafe` block, the `unsafe` keyword is required in
+/// each entry. The caller is responsible for upholding the safety requirements of
+/// [`PureClone`](crate::clone::PureClone) for every listed type.
+///
+/// # Examples
+///
+/// ```
+/// use clone_cell::{cell::Cell, clone::PureClone, pure_clone_remote};
+///
+/// pub struct Id(u32);
+///
+/// impl Clone for Id {
+///     fn clone(&self) -> Self {
+///         Self(self.0)
+///     }
+/// }
+///
+/// #[derive(Clone)]
+/// pub struct Wrapper<T>(pub T);
+///
+/// pure_clone_remote! {
+///     unsafe impl PureClone for Id;
+///     unsafe impl<T: PureClone> PureClone for Wrapper<T>;
+/// }
+///
+/// let c = Cell::new(Wrapper(Id(42)));
+/// assert_eq!(c.get().0 .0, 42);
+/// ```
+#[macro_export]
+macro_rules! pure_clone_remote {
+    () => {};
+    (unsafe impl $($rest:tt)*) => {
+        $crate::pure_clone_remote! { @generics [] $($rest)* }
+    };
+    (@generics [$($generics:tt)*] PureClone for $ty:ty; $($rest:tt)*) => {
+        unsafe impl $($generics)* $crate::clone::PureClone for $ty {}
+        $crate::pure_clone_remote! { $($rest)* }
+    };
+    (@generics [$($generics:tt)*] PureClone for $ty:ty where $($rest:tt)*) => {
+        $crate::pure_clone_remote! { @where [$($generics)*] [$ty] [] $($rest)* }
+    };
+    (@generics [$($generics:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::pure_clone_remote! { @generics [$($generics)* $next] $($rest)* }
+    };
+    (@where [$($generics:tt)*] [$ty:ty] [$($predicates:tt)*] ; $($rest:tt)*) => {
+        unsafe impl $($generics)* $crate::clone::PureClone for $ty where $($predicates)* {}
+        $crate::pure_clone_remote! { $($rest)* }
+    };
+    (@where [$($generics:tt)*] [$ty:ty] [$($predicates:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::pure_clone_remote! { @where [$($generics)*] [$ty] [$($predicates)* $next] $($rest)* }
+    };
+}
+
+/// Declares `PureClone` impls for `Copy` types defined in the calling crate.
+///
+/// This is a shorthand for [`pure_clone_remote!`] when all the types are `Copy` and not generic,
+/// such as IDs, flags and units. Each type is also asserted to be `Copy` at compile time, so the
+/// macro cannot be used for a type that is only `Clone`. Like `pure_clone_remote!`, it does not get
+/// around the orphan rules.
+///
+/// # Safety
+///
+/// Although the macro can be called without an `unsafe` block, the `unsafe` keyword is required
+/// before each type. The caller is responsible for upholding the safety requirements of
+/// [`PureClone`](crate::clone::PureClone) for every listed type. Being `Copy` is not enough: a
+/// `Copy` type can still have a hand-written `Clone` impl that is not pure. The types should use
+/// `#[derive(Clone, Copy)]` or implement `clone` as `*self`.
+///
+/// # Examples
+///
+/// ```
+/// use clone_cell::{cell::Cell, impl_pure_clone_for_copy};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// pub struct Id(u32);
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// pub struct Meters(f64);
+///
+/// impl_pure_clone_for_copy!(unsafe Id, unsafe Meters);
+///
+/// let c = Cell::new(Some(Id(42)));
+/// assert_eq!(c.get(), Some(Id(42)));
+/// ```
+///
+/// Compilation fails if any of the types is not `Copy`:
+/// ```compile_fail
+/// use clone_cell::impl_pure_clone_for_copy;
+///
+/// #[derive(Clone)]
+/// pub struct Name(String);
+///
+/// impl_pure_clone_for_copy!(unsafe Name);
+/// ```
+#[macro_export]
+macro_rules! impl_pure_clone_for_copy {
+    ($(unsafe $ty:ty),+ $(,)?) => {
+        $(unsafe impl $crate::clone::PureClone for $ty {})+
+        const _: () = {
+            $($crate::__private::assert_copy::<$ty>();)+
+        };
+    };
+}
+
+/// Defines a `struct` or `enum` along with `Clone` and `PureClone` impls for it, without using a
+/// proc macro.
+///
+/// This is a declarative alternative to the [`PureClone`](derive@crate::clone::PureClone) derive
+/// macro for builds where proc macros are not available. Like the derive macro, it generates a
+/// `Clone` impl that clones each field with `Clone::clone`, and a `PureClone` impl that requires
+/// each field to be `PureClone`. Any number of items can be given in one invocation.
+///
+/// # Limitations
+///
+/// This macro only understands a subset of the item syntax:
+/// - Generic parameters must be plain lifetimes or type parameters, e.g., `<'a, T>`. Bounds must
+///   be given in a `where` clause instead, and defaults and const generics are not supported.
+/// - Tuple structs and tuple variants can have at most 12 fields.
+/// - `#[cfg(...)]` on fields and variants, and `#[repr(packed)]`, are not supported.
+///
+/// Unlike the derive macro, the type of every field (not only of those that reference a generic
+/// parameter) is added as a bound to the generated impls. As a result, recursive types such as
+/// `struct List(Option<Box<List>>)` overflow when the impls are used. Use the derive macro for
+/// these.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{cell::Cell, pure_clone};
+///
+/// pure_clone! {
+///     struct Foo<T> {
+///         p: Rc<T>,
+///         x: i32,
+///     }
+///
+///     #[derive(Debug, PartialEq)]
+///     enum Bar<'a, T>
+///     where
+///         T: ?Sized,
+///     {
+///         X,
+///         Y(&'a T, u8),
+///         Z { t: Rc<T> },
+///     }
+/// }
+///
+/// let c = Cell::new(Foo {
+///     p: Rc::new(42),
+///     x: 21,
+/// });
+/// assert_eq!(*c.get().p, 42);
+/// let c = Cell::new(Bar::Y("bar", 1));
+/// assert_eq!(c.get(), Bar::Y("bar", 1));
+/// ```
+#[macro_export]
+macro_rules! pure_clone {
+    () => {};
+
+    // Structs.
+    (@struct $head:tt $name:ident $params:tt { $($body:tt)* } $($rest:tt)*) => {
+        $crate::pure_clone! { @named $head $name $params [] { $($body)* } }
+        $crate::pure_clone! { $($rest)* }
+    };
+    (@struct $head:tt $name:ident $params:tt ( $($body:tt)* ) ; $($rest:tt)*) => {
+        $crate::pure_clone! { @tuple $head $name $params [] [] [_0 _1 _2 _3 _4 _5 _6 _7 _8 _9 _10 _11] $($body)* }
+        $crate::pure_clone! { $($rest)* }
+    };
+    (@struct $head:tt $name:ident $params:tt ( $($body:tt)* ) where $($rest:tt)*) => {
+        $crate::pure_clone! { @tuple_where $head $name $params [$($body)*] [] $($rest)* }
+    };
+    (@struct $head:tt $name:ident $params:tt ; $($rest:tt)*) => {
+        $crate::pure_clone! { @unit $head $name $params }
+        $crate::pure_clone! { $($rest)* }
+    };
+    (@struct $head:tt $name:ident $params:tt where $($rest:tt)*) => {
+        $crate::pure_clone! { @struct_where $head $name $params [] $($rest)* }
+    };
+    (@struct_where $head:tt $name:ident $params:tt $where:tt { $($body:tt)* } $($rest:tt)*) => {
+        $crate::pure_clone! { @named $head $name $params $where { $($body)* } }
+        $crate::pure_clone! { $($rest)* }
+    };
+    (@struct_where $head:tt $name:ident $params:tt [$($where:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::pure_clone! { @struct_where $head $name $params [$($where)* $next] $($rest)* }
+    };
+    (@tuple_where $head:tt $name:ident $params:tt [$($body:tt)*] $where:tt ; $($rest:tt)*) => {
+        $crate::pure_clone! { @tuple $head $name $params $where [] [_0 _1 _2 _3 _4 _5 _6 _7 _8 _9 _10 _11] $($body)* }
+        $crate::pure_clone! { $($rest)* }
+    };
+    (@tuple_where $head:tt $name:ident $params:tt $body:tt [$($where:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::pure_clone! { @tuple_where $head $name $params $body [$($where)* $next] $($rest)* }
+    };
+    (@named [$($head:tt)*] $name:ident [$($param:tt),*] [$($where:tt)*] {
+        $($(#[$fattr:meta])* $fvis:vis $field:ident : $fty:ty),* $(,)?
+    }) => {
+        $($head)* struct $name<$($param),*>
+        where
+            $($where)*
+        {
+            $($(#[$fattr])* $fvis $field: $fty),*
+        }
+
+        impl<$($param),*> ::core::clone::Clone for $name<$($param),*>
+        where
+            $($fty: ::core::clone::Clone,)*
+            $($where)*
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    $($field: ::core::clone::Clone::clone(&self.$field)),*
+                }
+            }
+        }
+
+        unsafe impl<$($param),*> $crate::clone::PureClone for $name<$($param),*>
+        where
+            $($fty: $crate::clone::PureClone,)*
+            $($where)*
+        {
+        }
+    };
+    (@tuple [$($head:tt)*] $name:ident [$($param:tt),*] [$($where:tt)*]
+        [$([$binding:ident [$($fattr:tt)*] $fty:ty])*] $names:tt) => {
+        $($head)* struct $name<$($param),*>($($($fattr)* $fty),*)
+        where
+            $($where)*;
+
+        impl<$($param),*> ::core::clone::Clone for $name<$($param),*>
+        where
+            $($fty: ::core::clone::Clone,)*
+            $($where)*
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                let Self($(ref $binding),*) = *self;
+                Self($(::core::clone::Clone::clone($binding)),*)
+            }
+        }
+
+        unsafe impl<$($param),*> $crate::clone::PureClone for $name<$($param),*>
+        where
+            $($fty: $crate::clone::PureClone,)*
+            $($where)*
+        {
+        }
+    };
+    (@tuple $head:tt $name:ident $params:tt $where:tt [$($done:tt)*] [$binding:ident $($names:ident)*]
+        $(#[$fattr:meta])* $fvis:vis $fty:ty $(, $($rest:tt)*)?) => {
+        $crate::pure_clone! {
+            @tuple $head $name $params $where
+            [$($done)* [$binding [$(#[$fattr])* $fvis] $fty]] [$($names)*]
+            $($($rest)*)?
+        }
+    };
+    (@unit [$($head:tt)*] $name:ident [$($param:tt),*]) => {
+        $($head)* struct $name<$($param),*>;
+
+        impl<$($param),*> ::core::clone::Clone for $name<$($param),*> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self
+            }
+        }
+
+        unsafe impl<$($param),*> $crate::clone::PureClone for $name<$($param),*> {}
+    };
+
+    // Enums.
+    (@enum $head:tt $name:ident $params:tt { $($body:tt)* } $($rest:tt)*) => {
+        $crate::pure_clone! { @enum_body $head $name $params [] { $($body)* } }
+        $crate::pure_clone! { $($rest)* }
+    };
+    (@enum $head:tt $name:ident $params:tt where $($rest:tt)*) => {
+        $crate::pure_clone! { @enum_where $head $name $params [] $($rest)* }
+    };
+    (@enum_where $head:tt $name:ident $params:tt $where:tt { $($body:tt)* } $($rest:tt)*) => {
+        $crate::pure_clone! { @enum_body $head $name $params $where { $($body)* } }
+        $crate::pure_clone! { $($rest)* }
+    };
+    (@enum_where $head:tt $name:ident $params:tt [$($where:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::pure_clone! { @enum_where $head $name $params [$($where)* $next] $($rest)* }
+    };
+    (@enum_body [$($head:tt)*] $name:ident [$($param:tt),*] [$($where:tt)*] { $($body:tt)* }) => {
+        $($head)* enum $name<$($param),*>
+        where
+            $($where)*
+        {
+            $($body)*
+        }
+
+        $crate::pure_clone! { @variants [$name [$($param),*] [$($where)*]] [] [] $($body)* }
+    };
+    (@variants [$name:ident [$($param:tt),*] [$($where:tt)*]] [$($arm:tt)*] [$($fty:ty,)*]) => {
+        impl<$($param),*> ::core::clone::Clone for $name<$($param),*>
+        where
+            $($fty: ::core::clone::Clone,)*
+            $($where)*
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                match *self {
+                    $($arm)*
+                }
+            }
+        }
+
+        unsafe impl<$($param),*> $crate::clone::PureClone for $name<$($param),*>
+        where
+            $($fty: $crate::clone::PureClone,)*
+            $($where)*
+        {
+        }
+    };
+    (@variants $ctx:tt [$($arm:tt)*] $tys:tt
+        $(#[$vattr:meta])* $variant:ident $(= $discriminant:expr)? $(, $($rest:tt)*)?) => {
+        $crate::pure_clone! {
+            @variants $ctx [$($arm)* Self::$variant => Self::$variant,] $tys $($($rest)*)?
+        }
+    };
+    (@variants $ctx:tt [$($arm:tt)*] [$($tys:tt)*]
+        $(#[$vattr:meta])* $variant:ident {
+            $($(#[$fattr:meta])* $field:ident : $fty:ty),* $(,)?
+        } $(, $($rest:tt)*)?) => {
+        $crate::pure_clone! {
+            @variants $ctx
+            [
+                $($arm)*
+                Self::$variant { $($field: ref $field),* } => Self::$variant {
+                    $($field: ::core::clone::Clone::clone($field)),*
+                },
+            ]
+            [$($tys)* $($fty,)*]
+            $($($rest)*)?
+        }
+    };
+    (@variants $ctx:tt $arms:tt $tys:tt
+        $(#[$vattr:meta])* $variant:ident ( $($fields:tt)* ) $(, $($rest:tt)*)?) => {
+        $crate::pure_clone! {
+            @variant_tuple $ctx $arms $tys $variant [] [_0 _1 _2 _3 _4 _5 _6 _7 _8 _9 _10 _11]
+            [$($fields)*] [$($($rest)*)?]
+        }
+    };
+    (@variant_tuple $ctx:tt [$($arm:tt)*] $tys:tt $variant:ident [$($binding:ident)*] $names:tt
+        [] [$($rest:tt)*]) => {
+        $crate::pure_clone! {
+            @variants $ctx
+            [
+                $($arm)*
+                Self::$variant($(ref $binding),*) => Self::$variant(
+                    $(::core::clone::Clone::clone($binding)),*
+                ),
+            ]
+            $tys
+            $($rest)*
+        }
+    };
+    (@variant_tuple $ctx:tt $arms:tt [$($tys:tt)*] $variant:ident [$($done:ident)*]
+        [$binding:ident $($names:ident)*]
+        [$(#[$fattr:meta])* $fty:ty $(, $($fields:tt)*)?] $rest:tt) => {
+        $crate::pure_clone! {
+            @variant_tuple $ctx $arms [$($tys)* $fty,] $variant [$($done)* $binding] [$($names)*]
+            [$($($fields)*)?] $rest
+        }
+    };
+
+    // Entry points.
+    ($(#[$attr:meta])* $vis:vis struct $name:ident < $($param:tt),* > $($rest:tt)*) => {
+        $crate::pure_clone! { @struct [$(#[$attr])* $vis] $name [$($param),*] $($rest)* }
+    };
+    ($(#[$attr:meta])* $vis:vis struct $name:ident $($rest:tt)*) => {
+        $crate::pure_clone! { @struct [$(#[$attr])* $vis] $name [] $($rest)* }
+    };
+    ($(#[$attr:meta])* $vis:vis enum $name:ident < $($param:tt),* > $($rest:tt)*) => {
+        $crate::pure_clone! { @enum [$(#[$attr])* $vis] $name [$($param),*] $($rest)* }
+    };
+    ($(#[$attr:meta])* $vis:vis enum $name:ident $($rest:tt)*) => {
+        $crate::pure_clone! { @enum [$(#[$attr])* $vis] $name [] $($rest)* }
+    };
+}
+
+/// Defines a named callback type whose captures are listed explicitly, so that it can be
+/// `PureClone` and stored in a [`Cell`](crate::cell::Cell).
+///
+/// Closure types cannot implement `PureClone`. This macro instead defines a `struct` with one field
+/// per capture, with `Clone` and `PureClone` impls generated by [`pure_clone!`], a `new`
+/// constructor that takes the captures in order, and a `call` method that runs the body. The body
+/// reads the captures through `self`. The callback is `PureClone` exactly when all of its captures
+/// are, so nothing has to be asserted with `unsafe`.
+///
+/// Implementing the `Fn` traits for the generated type requires a nightly compiler, so the
+/// callback has to be invoked through `call`.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{cell::Cell, pure_callback};
+///
+/// pure_callback! {
+///     pub struct AddTo(base: Rc<i32>, times: u32) |&self, x: i32| -> i32 {
+///         *self.base * self.times as i32 + x
+///     }
+/// }
+///
+/// let c = Cell::new(AddTo::new(Rc::new(10), 2));
+/// assert_eq!(c.get().call(1), 21);
+/// ```
+#[macro_export]
+macro_rules! pure_callback {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident ($($capture:ident : $capture_ty:ty),* $(,)?)
+        |&$self:ident $(, $arg:ident : $arg_ty:ty)* $(,)?| $(-> $ret:ty)? $body:block
+    ) => {
+        $crate::pure_clone! {
+            $(#[$attr])*
+            $vis struct $name {
+                $($capture: $capture_ty,)*
+            }
+        }
+
+        impl $name {
+            /// Creates the callback from its captures.
+            #[allow(clippy::too_many_arguments)]
+            $vis fn new($($capture: $capture_ty),*) -> Self {
+                Self { $($capture),* }
+            }
+
+            /// Calls the callback.
+            $vis fn call(&$self $(, $arg: $arg_ty)*) $(-> $ret)? $body
+        }
+    };
+}
+
+/// Implements `Clone` and `PureClone` for boxed trait objects of a trait that has
+/// [`DynPureClone`](crate::clone::DynPureClone) as a supertrait.
+///
+/// `PureClone` cannot be a supertrait of a trait used as a trait object. Instead, declare the trait
+/// with `DynPureClone` as a supertrait, which every `'static` `PureClone` type implements, and call
+/// this macro with the name of the trait. `Box<dyn Trait>` then becomes `PureClone`, along with the
+/// `Send` and `Sync` variants of the trait object. As with `Clone` impls written by hand, the macro
+/// must be called in the crate that defines the trait.
+///
+/// # Examples
+///
+/// ```
+/// use clone_cell::{cell::Cell, clone::{DynPureClone, PureClone}, pure_clone_trait_object};
+///
+/// trait Shape: DynPureClone {
+///     fn area(&self) -> f64;
+/// }
+///
+/// pure_clone_trait_object!(Shape);
+///
+/// #[derive(Clone)]
+/// struct Square(f64);
+///
+/// unsafe impl PureClone for Square {}
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f64 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// let c: Cell<Box<dyn Shape>> = Cell::new(Box::new(Square(2.0)));
+/// assert_eq!(c.get().area(), 4.0);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! pure_clone_trait_object {
+    (@impl $ty:ty) => {
+        impl ::core::clone::Clone for $crate::__private::Box<$ty> {
+            fn clone(&self) -> Self {
+                $crate::__private::pure_clone_box(&**self)
+            }
+        }
+
+        unsafe impl $crate::clone::PureClone for $crate::__private::Box<$ty> {}
+    };
+    ($($path:tt)+) => {
+        $crate::pure_clone_trait_object! { @impl dyn $($path)+ }
+        $crate::pure_clone_trait_object! { @impl dyn $($path)+ + ::core::marker::Send }
+        $crate::pure_clone_trait_object! { @impl dyn $($path)+ + ::core::marker::Sync }
+        $crate::pure_clone_trait_object! {
+            @impl dyn $($path)+ + ::core::marker::Send + ::core::marker::Sync
+        }
+    };
+}
+
+/// Asserts at compile time that each of the given types implements
+/// [`PureClone`](crate::clone::PureClone).
+///
+/// This expands to a `const` item and can be used anywhere an item can, including at the module
+/// level and in `no_std` crates. It is useful in tests, to make sure that a type does not silently
+/// stop being `PureClone`, e.g., after a field is changed or a feature is disabled.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_cell::{assert_pure_clone, clone::PureClone};
+///
+/// #[derive(Clone)]
+/// struct Id(u32);
+///
+/// unsafe impl PureClone for Id {}
+///
+/// assert_pure_clone!(Id, Rc<Id>, Option<Id>);
+/// ```
+///
+/// Compilation fails if any of the types is not `PureClone`:
+/// ```compile_fail
+/// use std::cell::RefCell;
+/// use clone_cell::assert_pure_clone;
+///
+/// assert_pure_clone!(i32, RefCell<i32>);
+/// ```
+#[macro_export]
+macro_rules! assert_pure_clone {
+    ($($ty:ty),+ $(,)?) => {
+        const _: () = {
+            $($crate::__private::assert_pure_clone::<$ty>();)+
+        };
+    };
+}
+
+/// Asserts at compile time that none of the given types implements
+/// [`PureClone`](crate::clone::PureClone).
+///
+/// This is the counterpart of [`assert_pure_clone!`] and can be used in the same places. It is
+/// useful to document (and test) that a type is deliberately not `PureClone`.
+///
+/// Because the check relies on type inference being ambiguous when the type implements
+/// `PureClone`, a failing assertion is reported as a "type annotations needed" error rather than an
+/// error that mentions `PureClone`. The types must not contain generic parameters.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use clone_cell::{assert_not_pure_clone, cell::Cell};
+///
+/// assert_not_pure_clone!(RefCell<i32>, Cell<i32>, Box<dyn Fn()>);
+/// ```
+///
+/// Compilation fails if any of the types is `PureClone`:
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use clone_cell::assert_not_pure_clone;
+///
+/// assert_not_pure_clone!(Rc<i32>);
+/// ```
+#[macro_export]
+macro_rules! assert_not_pure_clone {
+    ($($ty:ty),+ $(,)?) => {
+        const _: fn() = || {
+            // If `$ty` is `PureClone`, both impls of the helper trait apply, so the type parameter
+            // cannot be inferred.
+            $(let _ = <$ty as $crate::__private::AmbiguousIfPureClone<_>>::some_item;)+
+        };
+    };
+}
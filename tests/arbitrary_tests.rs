@@ -0,0 +1,49 @@
+#![cfg(feature = "arbitrary")]
+
+use std::rc::Rc;
+
+use arbitrary::{Arbitrary, Unstructured};
+use clone_cell::cell::Cell;
+
+#[derive(Arbitrary, Debug)]
+struct Model {
+    count: Cell<u16>,
+    enabled: Cell<bool>,
+    name: Cell<Rc<str>>,
+}
+
+#[test]
+fn derived_struct() {
+    let bytes = [0x2a, 0x01, 0x01, 3, b'f', b'o', b'o', 0];
+    let model = Model::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+    // The fields are generated as if they were not in `Cell`s.
+    let mut u = Unstructured::new(&bytes);
+    assert_eq!(model.count.get(), u16::arbitrary(&mut u).unwrap());
+    assert_eq!(model.enabled.get(), bool::arbitrary(&mut u).unwrap());
+    assert_eq!(model.name.get(), Rc::<str>::arbitrary(&mut u).unwrap());
+    assert_eq!(model.count.get(), 0x012a);
+    assert!(model.enabled.get());
+
+    // The same input always generates the same value.
+    let again = Model::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    assert_eq!(format!("{model:?}"), format!("{again:?}"));
+}
+
+#[test]
+fn forwards_to_inner() {
+    let bytes = [7, 0, 0, 0, 1, 2];
+    let c = Cell::<u32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    assert_eq!(c.get(), 7);
+    assert_eq!(Cell::<u32>::size_hint(0), u32::size_hint(0));
+    assert_eq!(
+        Cell::<(u8, Vec<u8>)>::size_hint(0),
+        <(u8, Vec<u8>)>::size_hint(0)
+    );
+
+    let c = Cell::<Vec<u8>>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+    assert_eq!(
+        c.get(),
+        Vec::<u8>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap()
+    );
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "borsh")]
+
+use std::rc::Rc;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use clone_cell::cell::Cell;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct Account {
+    balance: Cell<u64>,
+    owner: Cell<[u8; 4]>,
+    memo: Cell<Option<String>>,
+    history: Cell<Rc<Vec<u32>>>,
+}
+
+/// `Account` without cells, as declared on chain.
+#[derive(BorshDeserialize, BorshSerialize)]
+struct PlainAccount {
+    balance: u64,
+    owner: [u8; 4],
+    memo: Option<String>,
+    history: Rc<Vec<u32>>,
+}
+
+#[test]
+fn round_trip() {
+    let account = Account {
+        balance: Cell::new(1_000),
+        owner: Cell::new(*b"abcd"),
+        memo: Cell::new(Some("hi".to_owned())),
+        history: Cell::new(Rc::new(vec![1, 2, 3])),
+    };
+    let bytes = borsh::to_vec(&account).unwrap();
+    let plain = PlainAccount {
+        balance: 1_000,
+        owner: *b"abcd",
+        memo: Some("hi".to_owned()),
+        history: Rc::new(vec![1, 2, 3]),
+    };
+    assert_eq!(bytes, borsh::to_vec(&plain).unwrap());
+
+    let back = borsh::from_slice::<Account>(&bytes).unwrap();
+    assert_eq!(back.balance.get(), 1_000);
+    assert_eq!(back.owner.get(), *b"abcd");
+    assert_eq!(back.memo.get().as_deref(), Some("hi"));
+    assert_eq!(*back.history.get(), [1, 2, 3]);
+
+    back.memo.set(None);
+    back.balance.set(0);
+    let bytes = borsh::to_vec(&back).unwrap();
+    let plain = borsh::from_slice::<PlainAccount>(&bytes).unwrap();
+    assert_eq!(plain.balance, 0);
+    assert_eq!(plain.memo, None);
+}
+
+#[test]
+fn transparent() {
+    let c = Cell::new(vec![Some(1i16), None, Some(-1)]);
+    assert_eq!(borsh::to_vec(&c).unwrap(), borsh::to_vec(&c.get()).unwrap());
+    assert!(borsh::from_slice::<Cell<u32>>(&[1, 2, 3]).is_err());
+}
+
+#[cfg(feature = "borsh-schema")]
+#[test]
+fn schema() {
+    use borsh::schema::BorshSchemaContainer;
+
+    assert_eq!(
+        BorshSchemaContainer::for_type::<Cell<Vec<u32>>>(),
+        BorshSchemaContainer::for_type::<Vec<u32>>(),
+    );
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::Zeroable;
+use clone_cell::cell::Cell;
+
+#[derive(Zeroable)]
+#[repr(C)]
+struct Counters {
+    hits: Cell<u32>,
+    misses: Cell<u64>,
+    ratio: Cell<f32>,
+    last: Cell<Option<&'static u8>>,
+}
+
+#[test]
+fn zeroed() {
+    let c = Cell::<u32>::zeroed();
+    assert_eq!(c.get(), 0);
+
+    let counters = Counters::zeroed();
+    assert_eq!(counters.hits.get(), 0);
+    assert_eq!(counters.misses.get(), 0);
+    assert_eq!(counters.ratio.get(), 0.0);
+    assert_eq!(counters.last.get(), None);
+    counters.hits.set(1);
+    assert_eq!(counters.hits.get(), 1);
+
+    let cells: [Cell<i16>; 4] = Zeroable::zeroed();
+    assert!(cells.iter().all(|c| c.get() == 0));
+}
+
+/// The slice casts that `TransparentWrapper` would offer are covered by `Cell::from_mut` and
+/// `as_slice_of_cells`, which require `&mut [T]`.
+#[test]
+fn slice_of_cells() {
+    let mut buf = [1u8, 2, 3, 4];
+    let cells = Cell::from_mut(&mut buf[..]).as_slice_of_cells();
+    cells[0].set(cells[3].get());
+    assert_eq!(buf, [4, 2, 3, 4]);
+}
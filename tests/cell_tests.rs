@@ -1,8 +1,18 @@
+#![cfg(feature = "alloc")]
+#![cfg_attr(feature = "never_type", feature(never_type))]
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
 use std::mem::transmute;
+use std::num::{FpCategory, NonZeroU32, Saturating};
+use std::ptr::{self, NonNull};
 use std::rc::{Rc, Weak};
 use std::sync::Arc;
 
-use clone_cell::cell::Cell;
+use clone_cell::{
+    cell::{Cell, Flag, Versioned, WeakSlot},
+    clone::{DynPureClone, Pure, PureClone, PureEq, PureOrd},
+};
 
 #[test]
 fn copy_fields() {
@@ -87,7 +97,7 @@ fn bad_drop() {
     let c = Rc::new(Cell::new(None));
     c.set(Some(Foo { ptr: c.clone() }));
     c.set(None);
-    assert_eq!(c.take().is_none(), true);
+    assert!(c.take().is_none());
 }
 
 #[test]
@@ -134,7 +144,7 @@ fn cycle() {
     let weak_observable = Rc::downgrade(&observable);
     let observer = Observer::new(observable);
     observer.poke_observable();
-    assert_eq!(weak_observable.upgrade().is_none(), true);
+    assert!(weak_observable.upgrade().is_none());
 }
 
 fn as_cell_of_array<T, const N: usize>(c: &[Cell<T>; N]) -> &Cell<[T; N]> {
@@ -164,3 +174,1400 @@ fn swap_nonoverlap() {
     let x2: &Cell<[_; 2]> = as_cell_of_array(x[2..4].try_into().unwrap());
     x1.swap(x2);
 }
+
+#[test]
+fn swap_std() {
+    let c = Cell::new(21);
+    let s = std::cell::Cell::new(42);
+    c.swap_std(&s);
+    assert_eq!(c.get(), 42);
+    assert_eq!(s.get(), 21);
+
+    // A `Cell` and a `std` cell over the same memory are the same cell.
+    let mut x = 7;
+    let s = std::cell::Cell::from_mut(&mut x);
+    let c = unsafe { &*(s as *const std::cell::Cell<i32> as *const Cell<i32>) };
+    c.swap_std(s);
+    assert_eq!(s.get(), 7);
+}
+
+fn as_cell_of_slice<T>(c: &[Cell<T>]) -> &Cell<[T]> {
+    unsafe { &*(c as *const [Cell<T>] as *const Cell<[T]>) }
+}
+
+#[test]
+fn swap_with_disjoint() {
+    let mut front = [Rc::new(1), Rc::new(2), Rc::new(3)];
+    let mut back = [Rc::new(4), Rc::new(5), Rc::new(6)];
+    let f: &Cell<[Rc<i32>]> = Cell::from_mut(&mut front[..]);
+    let b: &Cell<[Rc<i32>]> = Cell::from_mut(&mut back[..]);
+    f.swap_with(b);
+    assert_eq!(
+        f.as_slice_of_cells()
+            .iter()
+            .map(|c| *c.get())
+            .collect::<Vec<_>>(),
+        [4, 5, 6],
+    );
+    assert_eq!(
+        b.as_slice_of_cells()
+            .iter()
+            .map(|c| *c.get())
+            .collect::<Vec<_>>(),
+        [1, 2, 3],
+    );
+
+    // Adjacent sub-slices of the same buffer do not overlap.
+    let x = [Cell::new(1), Cell::new(2), Cell::new(3), Cell::new(4)];
+    as_cell_of_slice(&x[0..2]).swap_with(as_cell_of_slice(&x[2..4]));
+    assert_eq!(x.map(|c| c.get()), [3, 4, 1, 2]);
+}
+
+#[test]
+fn swap_with_identical() {
+    let x = [Cell::new(vec![1]), Cell::new(vec![2]), Cell::new(vec![3])];
+    let s = as_cell_of_slice(&x);
+    s.swap_with(s);
+    assert_eq!(x.map(|c| c.get()), [vec![1], vec![2], vec![3]]);
+}
+
+#[test]
+#[should_panic]
+fn swap_with_overlap() {
+    let x = [Cell::new(vec![1]), Cell::new(vec![2]), Cell::new(vec![3])];
+    // This should panic.
+    as_cell_of_slice(&x[0..2]).swap_with(as_cell_of_slice(&x[1..3]));
+}
+
+#[test]
+#[should_panic]
+fn swap_with_different_lengths() {
+    let x = [Cell::new(1), Cell::new(2), Cell::new(3)];
+    // This should panic.
+    as_cell_of_slice(&x[0..1]).swap_with(as_cell_of_slice(&x[1..3]));
+}
+
+/// A value that counts how many times it has been cloned.
+struct Counted<T> {
+    value: T,
+    clones: Rc<std::cell::Cell<usize>>,
+}
+
+impl<T> Counted<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            clones: Rc::new(std::cell::Cell::new(0)),
+        }
+    }
+}
+
+impl<T> Clone for Counted<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        // Only mutates a `std::cell::Cell` that is never stored in a `clone_cell::cell::Cell`.
+        self.clones.set(self.clones.get() + 1);
+        Self {
+            value: self.value.clone(),
+            clones: self.clones.clone(),
+        }
+    }
+}
+
+unsafe impl<T> PureClone for Counted<T> where T: PureClone {}
+
+impl<T> PartialEq for Counted<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> PartialOrd for Counted<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+// Comparing only compares `value`.
+unsafe impl<T> PureEq for Counted<T> where T: PureEq {}
+
+unsafe impl<T> PureOrd for Counted<T> where T: PureOrd {}
+
+#[test]
+fn with() {
+    let v = Counted::new(vec![1, 2, 3]);
+    let clones = v.clones.clone();
+    let c = Cell::new(v);
+    let len = unsafe { c.with(|v| v.value.len()) };
+    assert_eq!(len, 3);
+    assert_eq!(clones.get(), 0);
+    assert_eq!(c.get().value, [1, 2, 3]);
+    assert_eq!(clones.get(), 1);
+}
+
+#[test]
+fn peek() {
+    let v = Counted::new(vec![1, 2, 3]);
+    let clones = v.clones.clone();
+    let mut c = Cell::new(v);
+    assert_eq!(c.peek().value.len(), 3);
+    assert_eq!(c.peek().value[2], 3);
+    assert_eq!(clones.get(), 0);
+    c.get_mut().value.push(4);
+    assert_eq!(c.peek().value.len(), 4);
+    assert_eq!(clones.get(), 0);
+}
+
+#[test]
+fn pure_eq() {
+    let (a, b) = (Counted::new(vec![1u8, 2, 3]), Counted::new(vec![1u8, 2, 3]));
+    let (a_clones, b_clones) = (a.clones.clone(), b.clones.clone());
+    let (a, b) = (Cell::new(a), Cell::new(b));
+
+    assert!(a.pure_eq(&b));
+    assert!(a.pure_eq(&a));
+    assert_eq!(a.pure_partial_cmp(&b), Some(Ordering::Equal));
+    assert_eq!((a_clones.get(), b_clones.get()), (0, 0));
+
+    // `==` compares clones of both values.
+    assert!(a == b);
+    assert_eq!((a_clones.get(), b_clones.get()), (1, 1));
+
+    b.set(Counted::new(vec![1, 2]));
+    assert!(!a.pure_eq(&b));
+    assert_eq!(a.pure_partial_cmp(&b), Some(Ordering::Greater));
+    assert_eq!(a_clones.get(), 1);
+
+    let (x, y) = (Cell::new(vec![1u8, 2]), Cell::new(vec![1u8, 3]));
+    assert_eq!(x.pure_cmp(&y), Ordering::Less);
+    assert_eq!(y.pure_cmp(&x), Ordering::Greater);
+    assert_eq!(Cell::new(f64::NAN).pure_partial_cmp(&Cell::new(0.0)), None);
+}
+
+#[test]
+fn generational_slot() {
+    /// A slot that is either empty (`None`) or occupied with a generation.
+    struct Slot {
+        generation: Cell<Option<NonZeroU32>>,
+    }
+
+    impl Slot {
+        fn occupy(&self) -> NonZeroU32 {
+            self.generation.update(|g| {
+                let next = g.map_or(1, |g| g.get().wrapping_add(1));
+                // Generation 0 is reserved, so skip it on wrap around.
+                Some(NonZeroU32::new(next).unwrap_or(NonZeroU32::MIN))
+            });
+            self.generation.get().unwrap()
+        }
+
+        fn is_current(&self, generation: NonZeroU32) -> bool {
+            self.generation.get() == Some(generation)
+        }
+    }
+
+    let slot = Slot {
+        generation: Cell::new(None),
+    };
+    assert!(slot.generation.get().is_none());
+    let g1 = slot.occupy();
+    assert_eq!(g1.get(), 1);
+    assert!(slot.is_current(g1));
+    let g2 = slot.occupy();
+    assert_eq!(g2.get(), 2);
+    assert!(!slot.is_current(g1));
+    assert!(slot.is_current(g2));
+    slot.generation.set(NonZeroU32::new(u32::MAX));
+    assert_eq!(slot.occupy(), NonZeroU32::MIN);
+}
+
+#[test]
+fn clone_into_vec() {
+    let p = Rc::new(42);
+    let c = Cell::new(vec![p.clone(), p.clone()]);
+    let mut dst = Vec::with_capacity(6);
+    let buf = dst.as_ptr();
+    for _ in 0..3 {
+        c.clone_into_vec(&mut dst);
+    }
+    assert_eq!(dst.len(), 6);
+    assert_eq!(dst.capacity(), 6);
+    assert_eq!(dst.as_ptr(), buf);
+    assert!(dst.iter().all(|q| Rc::ptr_eq(q, &p)));
+    assert_eq!(Rc::strong_count(&p), 9);
+}
+
+#[test]
+fn clone_from() {
+    let mut c = Cell::new(Vec::with_capacity(16));
+    c.clone_from(&Cell::new(vec![1, 2, 3]));
+    assert_eq!(c.get(), [1, 2, 3]);
+
+    let mut c = Cell::new(vec![String::from("old")]);
+    let source = Cell::new(vec!["foo".to_owned(), "bar".to_owned()]);
+    c.clone_from(&source);
+    assert_eq!(c.get(), ["foo", "bar"]);
+    assert_eq!(source.get(), ["foo", "bar"]);
+}
+
+#[test]
+fn vec_sort_and_dedup() {
+    let mut c = Cell::new(vec![5, 3, 5, 1, 3, 3]);
+    c.dedup();
+    assert_eq!(c.get(), [5, 3, 5, 1, 3]);
+    c.sort();
+    assert_eq!(c.get(), [1, 3, 3, 5, 5]);
+    c.dedup();
+    assert_eq!(c.get(), [1, 3, 5]);
+
+    let mut empty: Cell<Vec<i32>> = Cell::default();
+    empty.sort();
+    empty.dedup();
+    assert!(empty.into_inner().is_empty());
+}
+
+#[test]
+fn vec_as_slice_of_cells() {
+    let mut c = Cell::new(vec![Rc::new(0), Rc::new(1), Rc::new(2)]);
+    let cells = c.as_slice_of_cells();
+    cells[0].set(Rc::new(42));
+    cells[1].swap(&cells[2]);
+    let p = cells[0].get();
+    assert_eq!(Rc::strong_count(&p), 2);
+    assert_eq!(c.get().iter().map(|p| **p).collect::<Vec<_>>(), [42, 2, 1]);
+}
+
+#[test]
+fn boxed_unsized() {
+    let p = Rc::new(42);
+    let s: Cell<Box<[Rc<i32>]>> = Cell::new(vec![p.clone(), p.clone()].into_boxed_slice());
+    assert_eq!(s.get().len(), 2);
+    assert_eq!(Rc::strong_count(&p), 3);
+    drop(s);
+
+    let s: Cell<Box<str>> = Cell::new("foo".into());
+    s.set("bar".into());
+    assert_eq!(&*s.get(), "bar");
+
+    let d: Cell<Box<dyn DynPureClone>> = Cell::new(Box::new(p.clone()));
+    let b = d.get();
+    assert_eq!(Rc::strong_count(&p), 3);
+    d.set(b);
+    assert_eq!(Rc::strong_count(&p), 2);
+    drop(d);
+    assert_eq!(Rc::strong_count(&p), 1);
+}
+
+#[test]
+fn map_get() {
+    let p = Rc::new(1);
+    let c = Cell::new(Some(p.clone()));
+    assert_eq!(c.map_get(|x| x.map_or(0, |x| *x + 1)), 2);
+    assert_eq!(Rc::strong_count(&p), 2);
+    let taken = c.map_get(|x| {
+        // `f` gets its own clone.
+        assert_eq!(Rc::strong_count(&p), 3);
+        x
+    });
+    assert!(Rc::ptr_eq(&taken.unwrap(), &p));
+    assert!(c.get().is_some());
+}
+
+#[test]
+fn try_update() {
+    let c = Cell::new(Rc::new(1));
+    assert_eq!(c.try_update(|x| Ok::<_, ()>(Rc::new(*x + 1))), Ok(()));
+    assert_eq!(*c.get(), 2);
+
+    let old = c.get();
+    assert_eq!(Rc::strong_count(&old), 2);
+    let err = c.try_update(|x| {
+        // The closure sees a clone, so the stored value is shared while it runs.
+        assert_eq!(Rc::strong_count(&x), 3);
+        Err::<Rc<i32>, _>(*x)
+    });
+    assert_eq!(err, Err(2));
+    assert!(Rc::ptr_eq(&c.get(), &old));
+    assert_eq!(Rc::strong_count(&old), 2);
+}
+
+#[test]
+fn replace_returning_both() {
+    /// A change event for a reactive value.
+    #[derive(Debug, PartialEq)]
+    enum Diff {
+        Unchanged,
+        Changed { delta: i32 },
+    }
+
+    fn diff(old: &Rc<i32>, new: &Rc<i32>) -> Diff {
+        if Rc::ptr_eq(old, new) {
+            Diff::Unchanged
+        } else {
+            Diff::Changed {
+                delta: **new - **old,
+            }
+        }
+    }
+
+    let c = Cell::new(Rc::new(10));
+    let (old, new) = c.replace_returning_both(|x| Rc::new(**x * 3));
+    assert_eq!(diff(&old, &new), Diff::Changed { delta: 20 });
+    // One reference is held by the `Cell` and one by `new`.
+    assert!(Rc::ptr_eq(&new, &c.get()));
+    assert_eq!(Rc::strong_count(&new), 2);
+    assert_eq!(Rc::strong_count(&old), 1);
+
+    let (old, new) = c.replace_returning_both(Rc::clone);
+    assert_eq!(diff(&old, &new), Diff::Unchanged);
+    assert_eq!(*c.get(), 30);
+}
+
+#[test]
+fn take_and() {
+    let c = Cell::new(vec![1, 2, 3]);
+    assert_eq!(c.take_and(|v| v.into_iter().sum::<i32>()), 6);
+    assert_eq!(c.take_and(|v| v.into_iter().sum::<i32>()), 0);
+    c.set(vec![4, 5]);
+    assert_eq!(c.take_and(|v| v.into_iter().sum::<i32>()), 9);
+    assert!(c.into_inner().is_empty());
+}
+
+#[test]
+fn take_if() {
+    let queue = Rc::new(vec![1, 2, 3]);
+    let c = Cell::new(Some(queue.clone()));
+
+    // A failing predicate sees the current value and leaves it in place.
+    let taken = c.take_if(|q| {
+        assert!(Rc::ptr_eq(q.as_ref().unwrap(), &queue));
+        q.as_ref().is_some_and(|q| q.len() > 3)
+    });
+    assert_eq!(taken, None);
+    assert!(Rc::ptr_eq(&c.get().unwrap(), &queue));
+    assert_eq!(Rc::strong_count(&queue), 2);
+
+    let taken = c.take_if(|q| q.as_ref().is_some_and(|q| q.len() == 3));
+    assert!(Rc::ptr_eq(&taken.unwrap().unwrap(), &queue));
+    assert_eq!(c.get(), None);
+    assert_eq!(Rc::strong_count(&queue), 1);
+
+    // The default value is taken like any other.
+    assert_eq!(c.take_if(Option::is_none), Some(None));
+    assert_eq!(c.take_if(Option::is_some), None);
+}
+
+#[test]
+fn take_if_reentrant() {
+    let c = Cell::new(vec![1, 2, 3]);
+
+    // The predicate sees the default value in the `Cell`, and its write cannot change the value
+    // that was tested and returned.
+    let taken = c.take_if(|v| {
+        assert!(c.get().is_empty());
+        c.set(vec![4]);
+        v.len() == 3
+    });
+    assert_eq!(taken, Some(vec![1, 2, 3]));
+    assert_eq!(c.get(), [4]);
+
+    // A rejected value is put back over the write.
+    let c = Cell::new(vec![1, 2, 3]);
+    let taken = c.take_if(|_| {
+        c.set(vec![4]);
+        false
+    });
+    assert_eq!(taken, None);
+    assert_eq!(c.get(), [1, 2, 3]);
+}
+
+#[test]
+fn collect() {
+    use std::collections::{BTreeMap, VecDeque};
+
+    /// Generic code that only knows about `FromIterator`.
+    fn squares<C: FromIterator<u32>>(n: u32) -> C {
+        (1..=n).map(|i| i * i).collect()
+    }
+
+    let c: Cell<Vec<u32>> = squares(4);
+    assert_eq!(c.get(), [1, 4, 9, 16]);
+    let c: Cell<Vec<u32>> = c.get().into_iter().rev().collect();
+    assert_eq!(c.get(), [16, 9, 4, 1]);
+    let c: Cell<VecDeque<u32>> = squares(3);
+    assert_eq!(c.into_inner(), [1, 4, 9]);
+
+    let c: Cell<BTreeMap<_, _>> = [("b", 2), ("a", 1), ("b", 3)].into_iter().collect();
+    assert_eq!(
+        c.into_inner().into_iter().collect::<Vec<_>>(),
+        [("a", 1), ("b", 3)]
+    );
+
+    let c: Cell<String> = "hello".chars().rev().collect();
+    assert_eq!(c.get(), "olleh");
+    let c: Cell<String> = c.get().split('l').collect();
+    assert_eq!(c.get(), "oeh");
+    let c: Cell<String> = ["a", "b"].iter().map(|s| s.repeat(2)).collect();
+    assert_eq!(c.get(), "aabb");
+}
+
+#[test]
+fn zero_sized() {
+    use std::marker::PhantomData;
+    use std::mem::size_of;
+
+    struct NotClone;
+
+    assert_eq!(size_of::<Cell<()>>(), 0);
+    assert_eq!(size_of::<Cell<PhantomData<NotClone>>>(), 0);
+
+    let c = Cell::new(());
+    c.set(());
+    c.get();
+    assert_eq!(c.replace(()), ());
+    c.swap(&Cell::new(()));
+    c.update(|()| ());
+    c.take();
+    c.into_inner();
+
+    let c: Cell<PhantomData<NotClone>> = Cell::new(PhantomData);
+    let p: PhantomData<NotClone> = c.get();
+    c.set(p);
+    c.swap(&Cell::new(PhantomData));
+    assert_eq!(c.take(), PhantomData);
+
+    // Distinct zero-sized cells may share an address, but they never overlap.
+    let cs = [Cell::new(()), Cell::new(())];
+    cs[0].swap(&cs[1]);
+}
+
+#[test]
+fn unsafe_cell_round_trip() {
+    use std::cell::UnsafeCell;
+
+    let p = Rc::new(42);
+    let c = Cell::from_unsafe_cell(UnsafeCell::new(p.clone()));
+    assert_eq!(*c.get(), 42);
+    let mut u = c.into_unsafe_cell();
+    *u.get_mut() = Rc::new(21);
+    assert_eq!(Rc::strong_count(&p), 1);
+    let c = Cell::from_unsafe_cell(u);
+    assert_eq!(*c.get(), 21);
+    assert_eq!(*c.into_unsafe_cell().into_inner(), 21);
+}
+
+#[test]
+fn btree_set() {
+    let flags = Cell::new(BTreeSet::from([Rc::from("a")]));
+    let b: Rc<str> = Rc::from("b");
+    assert!(flags.insert_cloned(b.clone()));
+    assert!(!flags.insert_cloned(b.clone()));
+    assert_eq!(Rc::strong_count(&b), 2);
+    assert_eq!(flags.get().len(), 2);
+
+    assert!(flags.remove_cloned(&b));
+    assert_eq!(Rc::strong_count(&b), 1);
+    assert!(!flags.remove_cloned(&b));
+    assert!(!flags.remove_cloned(&Rc::from("c")));
+    assert_eq!(flags.get().into_iter().collect::<Vec<_>>(), [Rc::from("a")]);
+}
+
+#[test]
+fn compare_with_value() {
+    let hits = Cell::new(7);
+    assert!(hits < 10);
+    assert!(hits <= 7);
+    assert!(hits > -1);
+    assert!(hits >= 7);
+    assert!(hits == 7);
+    assert!(hits != 8);
+    assert_eq!(hits.partial_cmp(&7), Some(Ordering::Equal));
+
+    hits.set(12);
+    assert!(hits > 10);
+    assert!(hits >= 10);
+
+    let ratio = Cell::new(f64::NAN);
+    assert_eq!(ratio.partial_cmp(&0.5), None);
+    assert_eq!([ratio < 0.5, ratio >= 0.5, ratio == 0.5], [false; 3]);
+
+    let name = Cell::new(Rc::<str>::from("b"));
+    assert!(name > Rc::from("a"));
+    assert!(name == Rc::from("b"));
+}
+
+#[test]
+fn sum_and_product() {
+    /// Generic code that folds into any `Sum` type.
+    fn total<S: std::iter::Sum<u32>>(items: &[u32]) -> S {
+        items.iter().copied().sum()
+    }
+
+    let acc: Cell<u32> = total(&[1, 2, 3]);
+    assert_eq!(acc.get(), 6);
+
+    let cells = [Cell::new(2u32), Cell::new(3), Cell::new(4)];
+    let c: Cell<u32> = cells.iter().sum();
+    assert_eq!(c.get(), 9);
+    let c: Cell<u32> = cells.iter().product();
+    assert_eq!(c.get(), 24);
+    // The cells are only read.
+    assert!(cells.iter().map(Cell::get).eq([2, 3, 4]));
+
+    // Summing into a bare `u32` goes through `get` or `into_inner`.
+    assert_eq!(cells.iter().map(Cell::get).sum::<u32>(), 9);
+    let c: Cell<u32> = cells.into_iter().sum();
+    assert_eq!(c.get(), 9);
+    let c: Cell<u64> = [Cell::new(5u64), Cell::new(6)].into_iter().product();
+    assert_eq!(c.get(), 30);
+
+    let empty: [Cell<i64>; 0] = [];
+    assert_eq!(empty.iter().sum::<Cell<i64>>().get(), 0);
+    assert_eq!(empty.iter().product::<Cell<i64>>().get(), 1);
+}
+
+#[test]
+fn sort_direction() {
+    struct Column {
+        direction: Cell<Ordering>,
+    }
+
+    impl Column {
+        fn sort(&self, rows: &mut [u32]) {
+            let direction = self.direction.get();
+            rows.sort_by(|a, b| match direction {
+                Ordering::Greater => b.cmp(a),
+                _ => a.cmp(b),
+            });
+        }
+    }
+
+    let column = Column {
+        direction: Cell::new(Ordering::Less),
+    };
+    let mut rows = [3, 1, 2];
+    column.sort(&mut rows);
+    assert_eq!(rows, [1, 2, 3]);
+    column.direction.reverse();
+    assert_eq!(column.direction.get(), Ordering::Greater);
+    column.sort(&mut rows);
+    assert_eq!(rows, [3, 2, 1]);
+    column.direction.reverse();
+    assert_eq!(column.direction.get(), Ordering::Less);
+
+    let c = Cell::new(Ordering::Equal);
+    c.reverse();
+    assert_eq!(c.get(), Ordering::Equal);
+}
+
+#[test]
+fn duration_try_add_assign() {
+    use std::time::Duration;
+
+    /// Accumulates time spent in a section without panicking on overflow.
+    struct Timer {
+        total: Cell<Duration>,
+        saturated: Cell<bool>,
+    }
+
+    impl Timer {
+        fn record(&self, elapsed: Duration) {
+            if !self.total.try_add_assign(elapsed) {
+                self.saturated.set(true);
+            }
+        }
+    }
+
+    let timer = Timer {
+        total: Cell::new(Duration::ZERO),
+        saturated: Cell::new(false),
+    };
+    timer.record(Duration::from_millis(250));
+    timer.record(Duration::from_millis(750));
+    assert_eq!(timer.total.get(), Duration::from_secs(1));
+    assert!(!timer.saturated.get());
+
+    timer.record(Duration::MAX);
+    assert!(timer.saturated.get());
+    assert_eq!(timer.total.get(), Duration::from_secs(1));
+
+    let c = Cell::new(Duration::MAX - Duration::from_nanos(1));
+    assert!(c.try_add_assign(Duration::from_nanos(1)));
+    assert_eq!(c.get(), Duration::MAX);
+    assert!(!c.try_add_assign(Duration::from_nanos(1)));
+    assert_eq!(c.get(), Duration::MAX);
+}
+
+#[test]
+fn classify() {
+    let c = Cell::new(f64::NAN);
+    assert_eq!(c.classify(), FpCategory::Nan);
+    c.set(0.0);
+    assert_eq!(c.classify(), FpCategory::Zero);
+    let c = Cell::new(f32::NAN);
+    assert_eq!(c.classify(), FpCategory::Nan);
+    c.set(-0.0);
+    assert_eq!(c.classify(), FpCategory::Zero);
+    assert_eq!(Cell::new(c.classify()).get(), FpCategory::Zero);
+}
+
+#[test]
+fn pure_wrapper() {
+    // Stands in for a foreign `Copy` type whose `clone` is not pure.
+    #[derive(Copy, Debug, PartialEq)]
+    struct Evil(&'static std::cell::Cell<u32>);
+
+    #[allow(clippy::non_canonical_clone_impl)]
+    impl Clone for Evil {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            *self
+        }
+    }
+
+    let clones: &'static std::cell::Cell<u32> = Box::leak(Box::new(std::cell::Cell::new(0)));
+
+    // `new_copy` never calls `clone`.
+    let c = Cell::new(Pure::new_copy(Evil(clones)));
+    assert_eq!(*c.get(), Evil(clones));
+    assert_eq!(c.get().into_inner(), Evil(clones));
+    assert_eq!(clones.get(), 0);
+
+    // SAFETY: `Evil::clone` only mutates `clones`, which is not a `Cell` from this crate and never
+    // contains an `Evil`.
+    let c = Cell::new(unsafe { Pure::new(Evil(clones)) });
+    assert_eq!(*c.get(), Evil(clones));
+    assert_eq!(clones.get(), 1);
+
+    let mut p = Pure::new_copy((1, 'a'));
+    p.1 = 'b';
+    let c = Cell::new(p);
+    assert_eq!(*c.get(), (1, 'b'));
+    assert_eq!(format!("{:?}", c.get()), "Pure((1, 'b'))");
+}
+
+#[test]
+fn display() {
+    let c = Cell::new(-42);
+    assert_eq!(c.to_string(), "-42");
+    assert_eq!(format!("{c:>5}"), "  -42");
+
+    let c = Cell::new(String::from("hello"));
+    assert_eq!(format!("{c}, world"), "hello, world");
+    assert_eq!(format!("{c:.4}"), "hell");
+
+    let c = Cell::new(Saturating(u8::MAX));
+    c.set(c.get() + Saturating(1));
+    assert_eq!(c.to_string(), "255");
+}
+
+#[test]
+fn non_null_links() {
+    struct Node {
+        value: i32,
+        next: Cell<Option<NonNull<Node>>>,
+    }
+
+    let mut a = Node {
+        value: 1,
+        next: Cell::none(),
+    };
+    let b = Node {
+        value: 2,
+        next: Cell::none(),
+    };
+    assert!(a.next.is_null());
+    a.next.set_ptr(&b as *const Node as *mut Node);
+    assert!(!a.next.is_null());
+    assert!(b.next.is_null());
+    // SAFETY: `b` outlives this read.
+    assert_eq!(unsafe { a.next.get().unwrap().as_ref() }.value, 2);
+
+    // Link `b` back to `a`, then unlink `a`.
+    b.next.set_ptr(&mut a);
+    assert_eq!(b.next.get().map(NonNull::as_ptr), Some(&mut a as *mut Node));
+    a.next.set_ptr(ptr::null_mut());
+    assert!(a.next.is_null());
+    assert_eq!(a.value, 1);
+
+    let c: Cell<Option<NonNull<[u8]>>> = Cell::none();
+    c.set_ptr(ptr::slice_from_raw_parts_mut(
+        NonNull::dangling().as_ptr(),
+        0,
+    ));
+    assert!(!c.is_null());
+}
+
+#[test]
+fn result_with_rc_arms() {
+    #[derive(Debug)]
+    struct Response {
+        body: String,
+    }
+
+    #[derive(Debug)]
+    struct ErrorDetails {
+        code: u16,
+        message: String,
+    }
+
+    let response = Rc::new(Response {
+        body: "ok".to_owned(),
+    });
+    let error = Rc::new(ErrorDetails {
+        code: 503,
+        message: "unavailable".to_owned(),
+    });
+
+    let last: Cell<Result<Rc<Response>, Rc<ErrorDetails>>> = Cell::new(Ok(response.clone()));
+    let got = last.get();
+    assert!(Rc::ptr_eq(got.as_ref().unwrap(), &response));
+    assert_eq!(Rc::strong_count(&response), 3);
+    drop(got);
+    assert_eq!(Rc::strong_count(&response), 2);
+
+    last.set(Err(error.clone()));
+    assert_eq!(Rc::strong_count(&response), 1);
+    let got = last.get();
+    let details = got.as_ref().unwrap_err();
+    assert_eq!(
+        (details.code, details.message.as_str()),
+        (503, "unavailable")
+    );
+    assert!(Rc::ptr_eq(details, &error));
+    assert_eq!(Rc::strong_count(&error), 3);
+    drop(got);
+
+    last.set(Ok(response.clone()));
+    assert_eq!(Rc::strong_count(&error), 1);
+    assert_eq!(last.get().unwrap().body, "ok");
+    assert_eq!(Rc::strong_count(&response), 2);
+}
+
+#[test]
+fn get_owned() {
+    use std::borrow::Cow;
+
+    let c = Cell::new("foo");
+    let s: String = c.get_owned();
+    assert_eq!(s, "foo");
+
+    let p = Rc::new(1);
+    let v = [p.clone(), Rc::new(2)];
+    let c = Cell::new(&v[..]);
+    let owned: Vec<Rc<i32>> = c.get_owned();
+    assert_eq!(Rc::strong_count(&p), 3);
+    assert!(Rc::ptr_eq(&owned[0], &p));
+    c.set(&v[1..]);
+    assert_eq!(*c.get_owned()[0], 2);
+
+    let c: Cell<Cow<str>> = Cell::new(Cow::Borrowed("bar"));
+    let s: String = c.get_owned();
+    assert_eq!(s, "bar");
+    assert!(matches!(c.get(), Cow::Borrowed("bar")));
+    c.set(Cow::Owned(String::from("baz")));
+    assert_eq!(c.get_owned(), "baz");
+    assert_eq!(c.get(), "baz");
+}
+
+#[test]
+fn cow_byte_buffer() {
+    use std::borrow::Cow;
+
+    /// Holds the current token, borrowing from the input unless it had to be unescaped.
+    struct Lexer {
+        token: Cell<Cow<'static, [u8]>>,
+    }
+
+    impl Lexer {
+        fn feed(&self, input: &'static [u8]) {
+            if input.contains(&b'\\') {
+                let unescaped = input.iter().copied().filter(|&b| b != b'\\').collect();
+                self.token.set(Cow::Owned(unescaped));
+            } else {
+                self.token.set(Cow::Borrowed(input));
+            }
+        }
+    }
+
+    let lexer = Lexer {
+        token: Cell::new(Cow::Borrowed(b"")),
+    };
+    lexer.feed(b"plain");
+    let token = lexer.token.get();
+    assert!(matches!(token, Cow::Borrowed(b"plain")));
+    lexer.feed(b"esc\\aped");
+    assert!(matches!(lexer.token.get(), Cow::Owned(ref v) if v == b"escaped"));
+    // The earlier token is unaffected.
+    assert_eq!(&*token, b"plain");
+    assert_eq!(lexer.token.get_owned(), b"escaped");
+}
+
+#[test]
+fn control_flow_visitor() {
+    use std::ops::ControlFlow;
+
+    struct Tree {
+        value: i32,
+        children: Vec<Tree>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Summary {
+        sum: i32,
+        visited: usize,
+    }
+
+    /// Accumulates into `state` until the sum exceeds `limit`, then stops the walk.
+    fn walk(tree: &Tree, state: &Cell<ControlFlow<Rc<Summary>, Rc<Summary>>>, limit: i32) {
+        state.update(|flow| match flow {
+            ControlFlow::Continue(s) => {
+                let s = Summary {
+                    sum: s.sum + tree.value,
+                    visited: s.visited + 1,
+                };
+                if s.sum > limit {
+                    ControlFlow::Break(Rc::new(s))
+                } else {
+                    ControlFlow::Continue(Rc::new(s))
+                }
+            }
+            done => done,
+        });
+        for child in &tree.children {
+            if state.get().is_break() {
+                return;
+            }
+            walk(child, state, limit);
+        }
+    }
+
+    let leaf = |value| Tree {
+        value,
+        children: Vec::new(),
+    };
+    let tree = Tree {
+        value: 1,
+        children: vec![
+            Tree {
+                value: 2,
+                children: vec![leaf(3), leaf(4)],
+            },
+            leaf(5),
+        ],
+    };
+    let start = || {
+        Cell::new(ControlFlow::Continue(Rc::new(Summary {
+            sum: 0,
+            visited: 0,
+        })))
+    };
+
+    let state = start();
+    walk(&tree, &state, 100);
+    let expected = Summary {
+        sum: 15,
+        visited: 5,
+    };
+    assert_eq!(state.get(), ControlFlow::Continue(Rc::new(expected)));
+
+    let state = start();
+    walk(&tree, &state, 5);
+    let expected = Summary { sum: 6, visited: 3 };
+    assert_eq!(state.get(), ControlFlow::Break(Rc::new(expected)));
+    let summary = state.get().break_value().unwrap();
+    assert_eq!(Rc::strong_count(&summary), 2);
+}
+
+#[test]
+fn string_edits() {
+    let label = Cell::new(String::new());
+    label.push_str("Count");
+    label.push(':');
+    label.push(' ');
+    label.push_str("42");
+    assert_eq!(label.get(), "Count: 42");
+
+    label.truncate(7);
+    assert_eq!(label.get(), "Count: ");
+    label.push_str("43");
+    assert_eq!(label.get(), "Count: 43");
+    label.truncate(100);
+    assert_eq!(label.get(), "Count: 43");
+
+    let c = Cell::new(String::from("añb"));
+    c.truncate(3);
+    assert_eq!(c.get(), "añ");
+    c.truncate(0);
+    assert!(c.into_inner().is_empty());
+}
+
+#[test]
+fn socket_addr_port() {
+    use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6};
+
+    let v4 = Cell::new(SocketAddr::from(([10, 0, 0, 1], 80)));
+    v4.set_port(8080);
+    assert_eq!(v4.port(), 8080);
+    assert_eq!(v4.get(), SocketAddr::from(([10, 0, 0, 1], 8080)));
+
+    let addr = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 7, 3);
+    let v6 = Cell::new(SocketAddr::V6(addr));
+    v6.set_port(8443);
+    assert_eq!(v6.port(), 8443);
+    let SocketAddr::V6(changed) = v6.get() else {
+        panic!("expected an IPv6 address");
+    };
+    assert_eq!(changed.ip(), &Ipv6Addr::LOCALHOST);
+    assert_eq!((changed.flowinfo(), changed.scope_id()), (7, 3));
+}
+
+#[test]
+fn debug_peek() {
+    thread_local! {
+        static CLONES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    #[derive(Debug)]
+    struct Counted(i32);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.with(|c| c.set(c.get() + 1));
+            Self(self.0)
+        }
+    }
+
+    unsafe impl PureClone for Counted {}
+
+    let c = Cell::new(vec![Counted(1), Counted(2)]);
+    // SAFETY: The `Debug` impl of `Counted` does not touch `c`.
+    let peek = unsafe { c.debug_peek() };
+    assert_eq!(format!("{peek:?}"), "[Counted(1), Counted(2)]");
+    assert_eq!(CLONES.with(std::cell::Cell::get), 0);
+
+    assert_eq!(format!("{c:?}"), "Cell { value: [Counted(1), Counted(2)] }");
+    assert_eq!(CLONES.with(std::cell::Cell::get), 2);
+
+    let mut a = [3, 4];
+    let s: &Cell<[i32]> = Cell::from_mut(&mut a[..]);
+    // SAFETY: The `Debug` impl of `i32` does not touch `s`.
+    assert_eq!(format!("{:?}", unsafe { s.debug_peek() }), "[3, 4]");
+}
+
+#[test]
+fn debug_unsized() {
+    thread_local! {
+        static LIVE_CLONES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    /// Checks that only one clone is alive while formatting.
+    struct Tracked(Rc<i32>);
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            LIVE_CLONES.with(|c| c.set(c.get() + 1));
+            Self(self.0.clone())
+        }
+    }
+
+    unsafe impl PureClone for Tracked {}
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            LIVE_CLONES.with(|c| c.set(c.get().saturating_sub(1)));
+        }
+    }
+
+    impl std::fmt::Debug for Tracked {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            assert_eq!(LIVE_CLONES.with(std::cell::Cell::get), 1);
+            self.0.fmt(f)
+        }
+    }
+
+    let mut a = [Rc::new(1), Rc::new(2), Rc::new(3)];
+    let plain = format!("{:?}", &a[..]);
+    let pretty = format!("{:#?}", &a[..]);
+    let s: &Cell<[Rc<i32>]> = Cell::from_mut(&mut a[..]);
+    assert_eq!(format!("{s:?}"), format!("Cell {{ value: {plain} }}"));
+    assert_eq!(
+        format!("{s:#?}"),
+        format!(
+            "Cell {{\n    value: {},\n}}",
+            pretty.replace('\n', "\n    ")
+        ),
+    );
+    // Same as a sized `Cell` of the same elements.
+    let sized = Cell::new([Rc::new(1), Rc::new(2), Rc::new(3)]);
+    assert_eq!(format!("{s:?}"), format!("{sized:?}"));
+
+    let mut t = [Tracked(Rc::new(4)), Tracked(Rc::new(5))];
+    let s: &Cell<[Tracked]> = Cell::from_mut(&mut t[..]);
+    assert_eq!(format!("{s:?}"), "Cell { value: [4, 5] }");
+    assert_eq!(LIVE_CLONES.with(std::cell::Cell::get), 0);
+
+    let mut e: [i32; 0] = [];
+    assert_eq!(
+        format!("{:?}", Cell::from_mut(&mut e[..])),
+        "Cell { value: [] }"
+    );
+
+    let mut text = String::from("tab\t\"quote\" é");
+    let plain = format!("{:?}", text.as_str());
+    let s: &Cell<str> = Cell::from_mut(text.as_mut_str());
+    assert_eq!(format!("{s:?}"), format!("Cell {{ value: {plain} }}"));
+}
+
+#[test]
+fn const_table() {
+    const fn build_table() -> [Cell<u32>; 256] {
+        let mut table = [const { Cell::new(0) }; 256];
+        let mut i = 0;
+        while i < table.len() {
+            table[i] = Cell::new(i as u32 * 3);
+            i += 1;
+        }
+        table
+    }
+
+    const fn sum(table: &[Cell<u32>]) -> u32 {
+        let mut sum = 0;
+        let mut i = 0;
+        while i < table.len() {
+            sum += table[i].get_copy();
+            i += 1;
+        }
+        sum
+    }
+
+    const SUM: u32 = sum(&build_table());
+    const LAST: u32 = {
+        let [.., last] = build_table();
+        last.into_inner()
+    };
+    assert_eq!(SUM, 3 * 255 * 256 / 2);
+    assert_eq!(LAST, 765);
+
+    // The same table is mutable at run time.
+    let table = build_table();
+    table[1].set(42);
+    assert_eq!(table[1].get_copy(), 42);
+    assert_eq!(sum(&table), SUM - 3 + 42);
+}
+
+#[test]
+fn four_cc() {
+    let c = Cell::new(*b"ftyp");
+    assert!(c.matches(b"ftyp"));
+    assert!(!c.matches(b"FTYP"));
+    assert_eq!(c.as_u32_be(), u32::from_be_bytes(*b"ftyp"));
+    assert_eq!(c.as_u32_be(), 0x6674_7970);
+
+    c.set(*b"\0\0\0\x01");
+    assert_eq!(c.as_u32_be(), 1);
+}
+
+#[test]
+fn none() {
+    struct NotDefault;
+
+    #[derive(Default)]
+    struct Foo {
+        p: Cell<Option<Rc<NotDefault>>>,
+    }
+
+    let c = Cell::<Option<Rc<i32>>>::none();
+    assert_eq!(c.get(), None);
+    c.set(Some(Rc::new(42)));
+    assert_eq!(c.get().as_deref(), Some(&42));
+    assert!(Foo::default().p.get().is_none());
+}
+
+#[test]
+fn get_or_init() {
+    let c: Cell<Option<Rc<str>>> = Cell::new(None);
+    let mut calls = 0;
+    let p = c.get_or_init(|| {
+        calls += 1;
+        Rc::from("init")
+    });
+    assert_eq!(&*p, "init");
+    assert_eq!(Rc::strong_count(&p), 2);
+
+    let p2 = c.get_or_init(|| {
+        calls += 1;
+        Rc::from("again")
+    });
+    assert!(Rc::ptr_eq(&p, &p2));
+    assert_eq!(calls, 1);
+    assert_eq!(Rc::strong_count(&p), 3);
+    drop(c);
+    assert_eq!(Rc::strong_count(&p), 2);
+}
+
+#[test]
+fn get_or_init_reentrant() {
+    let c = Rc::new(Cell::new(None));
+    let inner = Rc::new(1);
+    let p = c.get_or_init(|| {
+        // Both a nested initialization and a plain write are overwritten.
+        assert_eq!(*c.get_or_init(|| inner.clone()), 1);
+        c.set(Some(Rc::new(2)));
+        Rc::new(3)
+    });
+    assert_eq!(*p, 3);
+    assert_eq!(*c.get().unwrap(), 3);
+    assert_eq!(Rc::strong_count(&inner), 1);
+}
+
+#[cfg(feature = "never_type")]
+#[test]
+fn never_type() {
+    let c: Cell<Result<i32, !>> = Cell::new(Ok(42));
+    assert_eq!(c.get(), Ok(42));
+    c.set(Ok(21));
+    assert_eq!(c.get(), Ok(21));
+}
+
+#[test]
+fn get_into() {
+    let first = Rc::new(1);
+    let second = Rc::new(2);
+    let c = Cell::new(first.clone());
+    let mut slot = second.clone();
+    c.get_into(&mut slot);
+    assert!(Rc::ptr_eq(&slot, &first));
+    assert_eq!(Rc::strong_count(&first), 3);
+    assert_eq!(Rc::strong_count(&second), 1);
+
+    // Reading the same value again leaves the counts unchanged.
+    c.get_into(&mut slot);
+    assert_eq!(Rc::strong_count(&first), 3);
+}
+
+#[test]
+fn get_into_drop_reenters() {
+    type Slot = Cell<Option<Rc<Guard>>>;
+
+    /// Clears the slot when dropped.
+    struct Guard(Weak<Slot>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            if let Some(slot) = self.0.upgrade() {
+                slot.set(None);
+            }
+        }
+    }
+
+    let slot: Rc<Slot> = Rc::new(Cell::new(None));
+    let stored = Rc::new(Guard(Weak::new()));
+    slot.set(Some(stored.clone()));
+    let mut dst = Some(Rc::new(Guard(Rc::downgrade(&slot))));
+    // Dropping the old value of `dst` clears `slot`, but only after its content has been cloned.
+    slot.get_into(&mut dst);
+    assert!(Rc::ptr_eq(dst.as_ref().unwrap(), &stored));
+    assert!(slot.get().is_none());
+    assert_eq!(Rc::strong_count(&stored), 2);
+}
+
+#[test]
+fn flag() {
+    let f = Flag::default();
+    assert!(!f.is_set());
+    f.set();
+    f.set();
+    assert!(f.is_set());
+    assert!(f.clone().is_set());
+    f.clear();
+    assert!(!f.is_set());
+    assert!(f.toggle());
+    assert!(f.is_set());
+    assert!(f.take());
+    assert!(!f.take());
+    f.toggle();
+    assert!(f.is_set());
+    assert_eq!(format!("{:?}", Flag::from(true)), "Flag(true)");
+}
+
+#[test]
+fn versioned() {
+    let v = Versioned::new(Rc::new(1));
+    assert_eq!(v.get_version(), 0);
+    v.set(Rc::new(2));
+    v.set(Rc::new(3));
+    assert_eq!(v.get_version(), 2);
+    assert_eq!(*v.replace(Rc::new(4)), 3);
+    assert_eq!(v.get_version(), 3);
+
+    // The value is set back to an equal one in between, which the version still detects.
+    let (seen, value) = v.get_versioned();
+    v.set(Rc::new(5));
+    v.set(value.clone());
+    assert_eq!(v.get(), value);
+    assert_eq!(v.compare_exchange(seen, Rc::new(6)), Err(5));
+    assert_eq!(*v.get(), 4);
+
+    let (seen, _) = v.get_versioned();
+    assert_eq!(v.compare_exchange(seen, Rc::new(6)), Ok(6));
+    assert_eq!(*v.get(), 6);
+    assert_eq!(v.compare_exchange(seen, Rc::new(7)), Err(6));
+
+    let w = v.clone();
+    w.set(Rc::new(8));
+    assert_eq!((v.get_version(), *v.get()), (6, 6));
+    assert_eq!((w.get_version(), *w.get()), (7, 8));
+    assert_eq!(
+        format!("{:?}", Versioned::from(1)),
+        "Versioned { version: 0, value: 1 }"
+    );
+    assert_eq!(*w.into_inner(), 8);
+}
+
+#[test]
+fn weak_slot() {
+    struct Subject {
+        observer: WeakSlot<Observer>,
+    }
+
+    struct Observer {
+        seen: Cell<u32>,
+    }
+
+    impl Subject {
+        fn notify(&self) -> bool {
+            match self.observer.upgrade() {
+                Some(observer) => {
+                    observer.seen.set(observer.seen.get() + 1);
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    let subject = Subject {
+        observer: WeakSlot::default(),
+    };
+    assert!(!subject.observer.is_alive());
+    assert!(!subject.notify());
+
+    let first = Rc::new(Observer { seen: Cell::new(0) });
+    subject.observer.set(&first);
+    assert!(subject.observer.is_alive());
+    assert!(subject.notify());
+    assert_eq!(first.seen.get(), 1);
+    assert_eq!(Rc::strong_count(&first), 1);
+    assert_eq!(Rc::weak_count(&first), 1);
+
+    // A clone refers to the same observer, but is replaced independently.
+    let copy = subject.observer.clone();
+    let second = Rc::new(Observer { seen: Cell::new(0) });
+    subject.observer.set(&second);
+    assert!(subject.notify());
+    assert_eq!((first.seen.get(), second.seen.get()), (1, 1));
+    assert!(Rc::ptr_eq(&copy.upgrade().unwrap(), &first));
+
+    drop(second);
+    assert!(!subject.observer.is_alive());
+    assert!(!subject.notify());
+    assert!(copy.is_alive());
+    copy.clear();
+    assert!(!copy.is_alive());
+    assert_eq!(Rc::weak_count(&first), 0);
+    assert_eq!(format!("{:?}", WeakSlot::from(&first)), "WeakSlot((Weak))");
+}
+
+#[test]
+fn ring_stress() {
+    /// Counts the `Node`s that are alive, including clones.
+    struct Node {
+        id: usize,
+        next: Option<Rc<Cell<Node>>>,
+        live: Rc<Cell<isize>>,
+    }
+
+    impl Node {
+        fn new(id: usize, next: Option<Rc<Cell<Node>>>, live: &Rc<Cell<isize>>) -> Self {
+            live.set(live.get() + 1);
+            Self {
+                id,
+                next,
+                live: live.clone(),
+            }
+        }
+    }
+
+    impl Clone for Node {
+        fn clone(&self) -> Self {
+            Self::new(self.id, self.next.clone(), &self.live)
+        }
+    }
+
+    unsafe impl PureClone for Node {}
+
+    impl Drop for Node {
+        fn drop(&mut self) {
+            self.live.set(self.live.get() - 1);
+        }
+    }
+
+    const N: usize = 64;
+    let live = Rc::new(Cell::new(0));
+    let nodes: Vec<Rc<Cell<Node>>> = (0..N)
+        .map(|id| Rc::new(Cell::new(Node::new(id, None, &live))))
+        .collect();
+    // Close the ring.
+    for (i, node) in nodes.iter().enumerate() {
+        node.set(Node::new(i, Some(nodes[(i + 1) % N].clone()), &live));
+    }
+    assert_eq!(live.get(), N as isize);
+    let weak: Vec<_> = nodes.iter().map(Rc::downgrade).collect();
+
+    // Walk the ring through clones while rewiring it. A simple LCG keeps this deterministic.
+    let mut seed = 0x2545_f491_u32;
+    let mut rand = |n: usize| {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (seed >> 16) as usize % n
+    };
+    let mut cursor = nodes[0].clone();
+    for _ in 0..10_000 {
+        let current = cursor.get();
+        match rand(4) {
+            // Skip the next node, dropping the link to it while `current` still holds it.
+            0 => {
+                let next = current.next.as_ref().unwrap().get();
+                cursor.set(Node::new(current.id, next.next.clone(), &live));
+            }
+            // Point at a random node.
+            1 => cursor.set(Node::new(current.id, Some(nodes[rand(N)].clone()), &live)),
+            // Exchange the contents of the current and a random node.
+            2 => cursor.swap(&nodes[rand(N)]),
+            _ => {}
+        }
+        let next = cursor.get().next.clone().unwrap();
+        cursor = next;
+    }
+    drop(cursor);
+
+    // Every node still has its own `Node`, and all clones are gone.
+    assert_eq!(live.get(), N as isize);
+
+    // Break every cycle, then drop the handles.
+    for node in &nodes {
+        let id = node.get().id;
+        node.set(Node::new(id, None, &live));
+    }
+    drop(nodes);
+    assert_eq!(live.get(), 0);
+    assert!(weak.iter().all(|w| w.upgrade().is_none()));
+}
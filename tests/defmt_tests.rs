@@ -0,0 +1,63 @@
+#![cfg(feature = "defmt")]
+
+use std::sync::Mutex;
+
+use clone_cell::cell::Cell;
+
+/// Collects the encoded frames instead of sending them to a probe.
+#[defmt::global_logger]
+struct MockLogger;
+
+defmt::timestamp!("");
+
+static FRAMES: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+static CURRENT: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+unsafe impl defmt::Logger for MockLogger {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {
+        let frame = std::mem::take(&mut *CURRENT.lock().unwrap());
+        FRAMES.lock().unwrap().push(frame);
+    }
+
+    unsafe fn write(bytes: &[u8]) {
+        CURRENT.lock().unwrap().extend_from_slice(bytes);
+    }
+}
+
+#[derive(defmt::Format)]
+struct Sensor {
+    id: u8,
+    reading: Cell<u16>,
+    calibrated: Cell<bool>,
+}
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+fn format() {
+    assert_format::<Cell<u32>>();
+    assert_format::<Cell<Option<i8>>>();
+    assert_format::<Sensor>();
+
+    let sensor = Sensor {
+        id: 3,
+        reading: Cell::new(0x1234),
+        calibrated: Cell::new(true),
+    };
+    defmt::println!("{}", sensor);
+    sensor.reading.set(0x5678);
+    defmt::println!("{}", sensor);
+
+    let frames = FRAMES.lock().unwrap();
+    assert_eq!(frames.len(), 2);
+    // The values are encoded in little-endian after the string indices.
+    let first = &frames[0];
+    let second = &frames[1];
+    assert!(first.windows(2).any(|w| w == [0x34, 0x12]));
+    assert!(second.windows(2).any(|w| w == [0x78, 0x56]));
+    assert!(!second.windows(2).any(|w| w == [0x34, 0x12]));
+}
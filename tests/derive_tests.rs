@@ -1,9 +1,13 @@
-#![cfg(feature = "derive")]
+#![cfg(all(feature = "alloc", feature = "derive"))]
 
+use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::Arc;
 
-use clone_cell::{cell::Cell, clone::PureClone};
+use clone_cell::{
+    cell::Cell,
+    clone::{pure_clone_impl, PureClone, TrustedPureClone},
+};
 
 #[test]
 fn pure_clone_struct() {
@@ -100,6 +104,92 @@ fn recursive() {
     assert_eq!(f.clone(), Foo(None));
 }
 
+#[test]
+fn recursive_generic() {
+    // Bounding these field types would make the impls depend on themselves.
+    #[derive(Debug, PartialEq, PureClone)]
+    struct List<T>(Option<Box<List<T>>>, T);
+
+    #[derive(Debug, PartialEq, PureClone)]
+    struct Tree<T> {
+        children: Vec<Self>,
+        value: Rc<T>,
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    enum Expr<'a, T> {
+        Leaf(&'a T),
+        Neg(Box<Expr<'a, T>>),
+        Add(Rc<[Self; 2]>),
+    }
+
+    let c = Cell::new(List(Some(Box::new(List(None, 2))), 1));
+    assert_eq!(c.get(), List(Some(Box::new(List(None, 2))), 1));
+
+    // `Tree<T>` is `PureClone` even though `T` isn't.
+    #[derive(Debug, PartialEq)]
+    struct NotClone;
+    let leaf = Tree {
+        children: vec![],
+        value: Rc::new(NotClone),
+    };
+    let c = Cell::new(Tree {
+        children: vec![leaf],
+        value: Rc::new(NotClone),
+    });
+    assert_eq!(c.get().children.len(), 1);
+
+    let x = 42;
+    let e = Expr::Add(Rc::new([
+        Expr::Leaf(&x),
+        Expr::Neg(Box::new(Expr::Leaf(&x))),
+    ]));
+    let c = Cell::new(e.pure_clone());
+    assert_eq!(c.get(), e);
+}
+
+#[test]
+fn clone_from() {
+    #[derive(Debug, PartialEq, PureClone)]
+    struct Foo<T> {
+        buf: Vec<u8>,
+        t: T,
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    enum Bar {
+        X(Vec<u8>),
+        Y { buf: Vec<u8> },
+    }
+
+    // `clone_from` reuses the buffer of each field when it is large enough.
+    let mut f = Foo {
+        buf: Vec::with_capacity(16),
+        t: Rc::new(0),
+    };
+    let ptr = f.buf.as_ptr();
+    let source = Foo {
+        buf: vec![1, 2, 3],
+        t: Rc::new(42),
+    };
+    f.clone_from(&source);
+    assert_eq!(f, source);
+    assert_eq!(f.buf.as_ptr(), ptr);
+
+    let mut b = Bar::X(Vec::with_capacity(16));
+    let ptr = match &b {
+        Bar::X(buf) => buf.as_ptr(),
+        Bar::Y { .. } => unreachable!(),
+    };
+    b.clone_from(&Bar::X(vec![1, 2, 3]));
+    assert_eq!(b, Bar::X(vec![1, 2, 3]));
+    assert!(matches!(&b, Bar::X(buf) if buf.as_ptr() == ptr));
+
+    // A different variant is cloned instead.
+    b.clone_from(&Bar::Y { buf: vec![4] });
+    assert_eq!(b, Bar::Y { buf: vec![4] });
+}
+
 #[test]
 fn lifetimes() {
     // TODO: Add another lifetime?
@@ -134,3 +224,803 @@ fn variant() {
     let b2 = b.pure_clone();
     assert_eq!(b, b2);
 }
+
+#[test]
+fn copy() {
+    #[derive(Debug, PartialEq, PureClone)]
+    #[pure_clone(copy)]
+    struct Foo {
+        x: i32,
+        y: f64,
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    #[pure_clone(copy)]
+    struct Bar<T> {
+        t: T,
+        foo: Foo,
+    }
+
+    let f = Foo { x: 42, y: -42.0 };
+    let f2 = f;
+    assert_eq!(f, f2);
+
+    let c = Cell::new(Bar { t: 'b', foo: f });
+    let b = c.get();
+    let b2 = b;
+    assert_eq!(b, b2);
+    assert_eq!(b.pure_clone(), Bar { t: 'b', foo: f2 });
+}
+
+#[test]
+fn variant_field_bounds() {
+    struct NotClone;
+
+    #[derive(PureClone)]
+    enum Msg<T, U, V> {
+        Ping,
+        Data(T),
+        Shared(Rc<U>),
+        Meta(Rc<str>, PhantomData<V>),
+    }
+
+    let c: Cell<Msg<i32, NotClone, NotClone>> = Cell::new(Msg::Data(42));
+    assert!(matches!(c.get(), Msg::Data(42)));
+    c.set(Msg::Shared(Rc::new(NotClone)));
+    assert!(matches!(c.get(), Msg::Shared(_)));
+    c.set(Msg::Meta(Rc::from("meta"), PhantomData));
+    assert!(matches!(c.get(), Msg::Meta(s, _) if &*s == "meta"));
+    c.set(Msg::Ping);
+    assert!(matches!(c.get(), Msg::Ping));
+}
+
+#[test]
+fn typestate() {
+    #[derive(Debug, PartialEq)]
+    struct Open;
+
+    #[derive(Debug, PartialEq)]
+    struct Closed;
+
+    // `Open` and `Closed` are never cloned, so they don't need to be `Clone`.
+    #[derive(Debug, PartialEq, PureClone)]
+    struct Handle<S>(u32, PhantomData<S>);
+
+    impl Handle<Closed> {
+        fn open(self) -> Handle<Open> {
+            Handle(self.0, PhantomData)
+        }
+    }
+
+    impl Handle<Open> {
+        fn close(self) -> Handle<Closed> {
+            Handle(self.0, PhantomData)
+        }
+    }
+
+    let open = Cell::new(Handle(42, PhantomData));
+    let closed = Cell::new(open.get().close());
+    assert_eq!(closed.get(), Handle::<Closed>(42, PhantomData));
+    closed.set(Handle(7, PhantomData));
+    open.set(closed.get().open());
+    assert_eq!(open.get(), Handle::<Open>(7, PhantomData));
+}
+
+#[test]
+fn hygiene() {
+    #[allow(dead_code, non_camel_case_types)]
+    mod shadowed {
+        use std::rc::Rc;
+
+        use super::PureClone;
+
+        // These shadow the crates the generated code refers to.
+        pub struct core;
+        mod clone_cell {}
+
+        #[derive(PureClone)]
+        pub struct Foo<T> {
+            pub p: Rc<T>,
+            pub x: i32,
+        }
+
+        #[derive(PureClone)]
+        #[pure_clone(copy)]
+        pub struct Bar(pub u8);
+    }
+
+    macro_rules! define {
+        ($name:ident, $ty:ty) => {
+            #[derive(PureClone)]
+            struct $name<T> {
+                t: T,
+                x: $ty,
+            }
+        };
+    }
+
+    define!(Baz, Rc<str>);
+
+    let c = Cell::new(shadowed::Foo {
+        p: Rc::new(42),
+        x: 21,
+    });
+    assert_eq!(*c.get().p, 42);
+    assert_eq!(c.get().x, 21);
+    assert_eq!(shadowed::Bar(42).pure_clone().0, 42);
+
+    let c = Cell::new(Baz {
+        t: 42,
+        x: Rc::from("x"),
+    });
+    assert_eq!(c.get().t, 42);
+    assert_eq!(&*c.get().x, "x");
+}
+
+#[test]
+fn const_generics() {
+    #[derive(Debug, PartialEq, PureClone)]
+    struct Buf<T, const N: usize> {
+        data: [T; N],
+        more: [T; N],
+        len: usize,
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    enum Chunk<T, const N: usize> {
+        Full([T; N]),
+        Partial(Buf<T, N>),
+    }
+
+    let b = Buf {
+        data: [Rc::new(1), Rc::new(2)],
+        more: [Rc::new(3), Rc::new(4)],
+        len: 2,
+    };
+    let c = Cell::new(Chunk::Partial(b.pure_clone()));
+    assert_eq!(c.get(), Chunk::Partial(b));
+    c.set(Chunk::Full([Rc::new(5), Rc::new(6)]));
+    assert_eq!(c.get(), Chunk::Full([Rc::new(5), Rc::new(6)]));
+}
+
+#[test]
+fn default_params() {
+    #[derive(Debug, PartialEq, PureClone)]
+    struct Foo<T = i32> {
+        t: T,
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    struct Buf<T = u8, const N: usize = 4> {
+        data: [T; N],
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    enum Bar<T = Rc<str>, const N: usize = 2> {
+        _X,
+        Y([T; N]),
+    }
+
+    let f: Foo = Foo { t: 42 };
+    assert_eq!(f.pure_clone(), Foo { t: 42 });
+    let b: Buf = Buf { data: [1, 2, 3, 4] };
+    assert_eq!(Cell::new(b).get().data, [1, 2, 3, 4]);
+    let c: Cell<Bar> = Cell::new(Bar::Y([Rc::from("a"), Rc::from("b")]));
+    assert_eq!(c.get(), Bar::Y([Rc::from("a"), Rc::from("b")]));
+    let c: Cell<Bar<char, 1>> = Cell::new(Bar::Y(['c']));
+    assert_eq!(c.get(), Bar::Y(['c']));
+}
+
+#[deny(private_bounds, private_interfaces)]
+pub mod public {
+    use std::rc::Rc;
+
+    use clone_cell::clone::PureClone;
+
+    #[derive(PureClone)]
+    struct Private(Rc<i32>);
+
+    // Only `T` is bounded, so `Private` does not leak into the generated impls.
+    #[derive(PureClone)]
+    pub struct Public<T> {
+        pub t: T,
+        private: Private,
+    }
+
+    impl<T> Public<T> {
+        pub fn new(t: T, i: i32) -> Self {
+            Self {
+                t,
+                private: Private(Rc::new(i)),
+            }
+        }
+
+        pub fn private(&self) -> i32 {
+            *self.private.0
+        }
+    }
+}
+
+#[test]
+fn private_field_type() {
+    let c = Cell::new(public::Public::new(Rc::new(42), 21));
+    assert_eq!(*c.get().t, 42);
+    assert_eq!(c.get().private(), 21);
+}
+
+mod renamed {
+    pub use clone_cell as inner;
+}
+
+#[allow(dead_code)]
+mod renamed_user {
+    use crate::renamed::inner::clone::PureClone;
+
+    // Shadows the default crate path.
+    mod clone_cell {}
+
+    #[derive(PureClone)]
+    #[pure_clone(crate = "crate::renamed::inner")]
+    pub struct Foo<T> {
+        pub t: T,
+        pub x: i32,
+    }
+}
+
+#[test]
+fn crate_path() {
+    let c = Cell::new(renamed_user::Foo {
+        t: Rc::new(42),
+        x: 21,
+    });
+    assert_eq!(*c.get().t, 42);
+    assert_eq!(c.get().x, 21);
+}
+
+#[test]
+fn packed() {
+    #[derive(Debug, PureClone)]
+    #[repr(C, packed)]
+    struct Foo {
+        a: u8,
+        b: u32,
+        c: (u16, u64),
+    }
+
+    #[derive(PureClone)]
+    #[repr(C, packed(2))]
+    struct Bar<'a, T>(u8, T, &'a str);
+
+    #[derive(PureClone)]
+    #[repr(C, packed)]
+    #[pure_clone(copy)]
+    struct Baz {
+        a: u8,
+        b: u64,
+    }
+
+    let c = Cell::new(Foo {
+        a: 1,
+        b: 2,
+        c: (3, 4),
+    });
+    let f = c.get();
+    assert_eq!({ f.a }, 1);
+    assert_eq!({ f.b }, 2);
+    assert_eq!({ f.c }, (3, 4));
+
+    let b = Bar(1, 'b', "bar").pure_clone();
+    assert_eq!({ b.0 }, 1);
+    assert_eq!({ b.1 }, 'b');
+    assert_eq!({ b.2 }, "bar");
+
+    let b = Baz { a: 1, b: 2 };
+    let b2 = b;
+    assert_eq!({ b.a }, { b2.a });
+    assert_eq!({ b.b }, { b2.b });
+}
+
+#[test]
+fn unsafe_trust_clone() {
+    #[derive(PureClone)]
+    #[pure_clone(unsafe_trust_clone)]
+    struct Versioned<T> {
+        value: Rc<T>,
+        version: u32,
+    }
+
+    impl<T> Clone for Versioned<T> {
+        fn clone(&self) -> Self {
+            Self {
+                value: self.value.clone(),
+                version: self.version + 1,
+            }
+        }
+    }
+
+    let p = Rc::new(42);
+    let c = Cell::new(Versioned {
+        value: p.clone(),
+        version: 0,
+    });
+    let v = c.get();
+    assert_eq!(v.version, 1);
+    assert_eq!(*v.value, 42);
+    assert_eq!(Rc::strong_count(&p), 3);
+    assert_eq!(v.pure_clone().version, 2);
+    assert_eq!(c.get().version, 1);
+}
+
+#[test]
+fn trusted() {
+    fn assert_trusted<T: TrustedPureClone>(t: T) -> T {
+        t.pure_clone()
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    #[pure_clone(trusted)]
+    struct Foo<T> {
+        id: u32,
+        shared: Rc<str>,
+        t: Option<T>,
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    #[pure_clone(trusted, copy)]
+    enum Bar {
+        X(char),
+        Y { foo: (i8, bool) },
+    }
+
+    let foo = Foo {
+        id: 1,
+        shared: Rc::from("foo"),
+        t: Some(Bar::Y { foo: (-1, true) }),
+    };
+    assert_eq!(assert_trusted(Cell::new(foo).get()).id, 1);
+    assert_eq!(assert_trusted(Bar::X('x')), Bar::X('x'));
+}
+
+#[test]
+fn derived_clone() {
+    #[derive(Clone, Debug, PartialEq, PureClone)]
+    #[pure_clone(unsafe_trust_clone)]
+    struct Foo<T>(T, Rc<str>);
+
+    #[derive(PureClone, Clone)]
+    #[pure_clone(unsafe_trust_clone, where = "T: Clone")]
+    struct Bar<T> {
+        p: Rc<T>,
+    }
+
+    let c = Cell::new(Foo(42, Rc::from("foo")));
+    assert_eq!(c.get(), Foo(42, Rc::from("foo")));
+    let c = Cell::new(Bar { p: Rc::new(42) });
+    assert_eq!(*c.get().p, 42);
+}
+
+#[test]
+fn empty_enum() {
+    #[derive(Debug, PartialEq, PureClone)]
+    enum Never {}
+
+    #[derive(PureClone)]
+    struct Wrapper<T>(Option<T>);
+
+    let c = Cell::new(Wrapper::<Never>(None));
+    assert_eq!(c.get().0, None);
+
+    let c: Cell<Result<i32, Never>> = Cell::new(Ok(42));
+    assert_eq!(c.get(), Ok(42));
+}
+
+#[test]
+fn where_predicates() {
+    trait Parser {
+        type Output;
+    }
+
+    // `Vec<P::Output>: PureClone` is inferred from the field, but it does not imply the
+    // `P::Output: Clone` bound that the hand-written `Clone` impl needs.
+    #[derive(PureClone)]
+    #[pure_clone(unsafe_trust_clone, where = "P::Output: PureClone, P: 'static")]
+    struct Parsed<P: Parser> {
+        outputs: Vec<P::Output>,
+    }
+
+    impl<P: Parser> Clone for Parsed<P>
+    where
+        P::Output: Clone,
+    {
+        fn clone(&self) -> Self {
+            Self {
+                outputs: self.outputs.clone(),
+            }
+        }
+    }
+
+    // Predicates from multiple attributes are all appended.
+    #[derive(PureClone)]
+    #[pure_clone(where = "T: Copy")]
+    #[pure_clone(where = "T: Default,")]
+    struct Pair<T>(T, T);
+
+    struct Digits;
+
+    impl Parser for Digits {
+        type Output = Rc<u8>;
+    }
+
+    let p = Rc::new(4);
+    let c: Cell<Parsed<Digits>> = Cell::new(Parsed {
+        outputs: vec![p.clone(), p.clone()],
+    });
+    assert_eq!(c.get().outputs.len(), 2);
+    assert_eq!(Rc::strong_count(&p), 3);
+
+    fn assert_pure_clone<T: PureClone>() {}
+    assert_pure_clone::<Pair<u8>>();
+}
+
+#[test]
+fn cfg_fields() {
+    // Fields and variants that are compiled out are removed before the derive sees them, so the
+    // generated code never refers to them. `cfg(test)` is on in this test harness.
+    #[derive(Debug, PartialEq, PureClone)]
+    struct Stats {
+        hits: u64,
+        #[cfg(test)]
+        counters: Vec<u64>,
+        #[cfg(not(test))]
+        missing: NotPureClone,
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    #[pure_clone(copy)]
+    struct Packet(u8, #[cfg(not(test))] NotPureClone, #[cfg(test)] u16);
+
+    #[derive(Debug, PartialEq, PureClone)]
+    enum Event {
+        Tick,
+        #[cfg(not(test))]
+        Missing(NotPureClone),
+        Data {
+            #[cfg(test)]
+            len: usize,
+            #[cfg(not(test))]
+            missing: NotPureClone,
+            #[cfg_attr(test, allow(unused))]
+            payload: Rc<[u8]>,
+        },
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq)]
+    struct NotPureClone;
+
+    let c = Cell::new(Stats {
+        hits: 1,
+        counters: vec![2, 3],
+    });
+    assert_eq!(
+        c.get(),
+        Stats {
+            hits: 1,
+            counters: vec![2, 3],
+        }
+    );
+
+    let p = Packet(1, 2);
+    let p2 = p;
+    assert_eq!(p, p2);
+
+    let c = Cell::new(Event::Data {
+        len: 3,
+        payload: Rc::from(&[1, 2, 3][..]),
+    });
+    assert!(matches!(c.get(), Event::Data { len: 3, .. }));
+    c.set(Event::Tick);
+    assert_eq!(c.get(), Event::Tick);
+}
+
+#[test]
+fn associated_types() {
+    trait Config {
+        type Storage;
+        type Nested: Config;
+    }
+
+    #[derive(PureClone)]
+    struct Node<T: Config, I: Iterator> {
+        value: <T as Config>::Storage,
+        nested: Option<Box<<T::Nested as Config>::Storage>>,
+        item: Vec<I::Item>,
+        next: Option<Rc<Node<T, I>>>,
+    }
+
+    struct Shared;
+
+    impl Config for Shared {
+        type Storage = Rc<i32>;
+        type Nested = Self;
+    }
+
+    let p = Rc::new(42);
+    let c: Cell<Node<Shared, std::vec::IntoIter<char>>> = Cell::new(Node {
+        value: p.clone(),
+        nested: Some(Box::new(p.clone())),
+        item: vec!['a'],
+        next: None,
+    });
+    let n = c.get();
+    assert_eq!(*n.value, 42);
+    assert_eq!(n.item, ['a']);
+    assert_eq!(Rc::strong_count(&p), 5);
+}
+
+#[test]
+fn look_alike_field_types() {
+    // These share names with types that are never `PureClone`, but they are.
+    #[derive(PureClone)]
+    struct RefCell<T>(T);
+
+    #[derive(PureClone)]
+    struct Mutex(Rc<str>);
+
+    mod sync {
+        pub type RwLock<T> = std::rc::Rc<T>;
+    }
+
+    #[derive(PureClone)]
+    struct Foo {
+        r: RefCell<i32>,
+        m: Mutex,
+        rw: sync::RwLock<i32>,
+        c: std::rc::Rc<Cell<i32>>,
+        f: Rc<dyn Fn() -> i32>,
+    }
+
+    let c = Cell::new(Foo {
+        r: RefCell(1),
+        m: Mutex(Rc::from("m")),
+        rw: Rc::new(2),
+        c: Rc::new(Cell::new(3)),
+        f: Rc::new(|| 4),
+    });
+    let f = c.get();
+    assert_eq!(f.r.0, 1);
+    assert_eq!(&*f.m.0, "m");
+    assert_eq!(*f.rw, 2);
+    assert_eq!(f.c.get(), 3);
+    assert_eq!((f.f)(), 4);
+}
+
+#[test]
+fn method_named_fields() {
+    #[derive(Debug, PartialEq, PureClone)]
+    struct Wire {
+        clone: Rc<str>,
+        pure_clone: u32,
+        self_: i32,
+        r#type: u8,
+        __binding_0: char,
+    }
+
+    #[derive(Debug, PartialEq, PureClone)]
+    enum Message {
+        Data { clone: Rc<str>, r#type: u8 },
+        Raw(Wire),
+    }
+
+    #[derive(PureClone)]
+    #[repr(C, packed)]
+    struct Packed {
+        clone: u16,
+        r#type: u8,
+    }
+
+    #[derive(PureClone)]
+    #[pure_clone(copy)]
+    struct Flags {
+        clone: bool,
+        pure_clone: bool,
+    }
+
+    let w = Wire {
+        clone: Rc::from("clone"),
+        pure_clone: 1,
+        self_: 2,
+        r#type: 3,
+        __binding_0: 'b',
+    };
+    let c = Cell::new(Message::Raw(w.pure_clone()));
+    assert_eq!(c.get(), Message::Raw(w));
+    c.set(Message::Data {
+        clone: Rc::from("data"),
+        r#type: 4,
+    });
+    assert!(matches!(c.get(), Message::Data { r#type: 4, .. }));
+
+    let p = Packed {
+        clone: 5,
+        r#type: 6,
+    }
+    .pure_clone();
+    assert_eq!({ p.clone }, 5);
+    assert_eq!({ p.r#type }, 6);
+
+    let f = Flags {
+        clone: true,
+        pure_clone: false,
+    }
+    .pure_clone();
+    assert!(f.clone && !f.pure_clone);
+}
+
+#[test]
+fn fn_pointer_fields() {
+    use std::ops::ControlFlow;
+
+    struct Event(u32);
+
+    #[derive(PureClone)]
+    struct Handlers<T> {
+        callback: fn(&Event) -> ControlFlow<()>,
+        parser: for<'a> fn(&'a str) -> Option<&'a str>,
+        map: fn(&T) -> T,
+        plain: fn(u32) -> u32,
+    }
+
+    #[derive(PureClone)]
+    enum Handler<T> {
+        Event(fn(&Event) -> ControlFlow<()>),
+        Parse {
+            parser: for<'a> fn(&'a str, &T) -> &'a str,
+        },
+    }
+
+    fn callback(e: &Event) -> ControlFlow<()> {
+        if e.0 == 0 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn parser(s: &str) -> Option<&str> {
+        s.strip_prefix('#')
+    }
+
+    fn map(x: &i32) -> i32 {
+        x * 2
+    }
+
+    let c = Cell::new(Handlers {
+        callback,
+        parser,
+        map,
+        plain: |x| x + 1,
+    });
+    assert!((c.get().callback)(&Event(0)).is_break());
+    assert_eq!((c.get().parser)("#tag"), Some("tag"));
+    assert_eq!((c.get().map)(&21), 42);
+    assert_eq!((c.get().plain)(41), 42);
+
+    let c: Cell<Handler<String>> = Cell::new(Handler::Event(callback));
+    assert!(matches!(c.get(), Handler::Event(f) if f(&Event(1)).is_continue()));
+    c.set(Handler::Parse {
+        parser: |s, _| s.trim(),
+    });
+    assert!(matches!(c.get(), Handler::Parse { parser } if parser(" x ", &String::new()) == "x"));
+}
+
+#[test]
+fn range_fields() {
+    use std::ops::RangeInclusive;
+
+    #[derive(PureClone)]
+    struct Window<Idx> {
+        range: RangeInclusive<Idx>,
+        data: Rc<[u8]>,
+    }
+
+    let data: Rc<[u8]> = Rc::from([1, 2, 3, 4]);
+    let c = Cell::new(Window {
+        range: 0..=1,
+        data: data.clone(),
+    });
+    let w = c.get();
+    c.set(Window {
+        range: w.range.start() + 1..=w.range.end() + 1,
+        ..w
+    });
+    let w = c.get();
+    assert_eq!(w.range, 1..=2);
+    assert_eq!(&w.data[w.range.clone()], [2, 3]);
+    assert_eq!(Rc::strong_count(&data), 3);
+}
+
+#[test]
+fn unsized_params() {
+    trait Observer {
+        fn id(&self) -> u32;
+    }
+
+    struct Logger;
+
+    impl Observer for Logger {
+        fn id(&self) -> u32 {
+            7
+        }
+    }
+
+    // `T` only appears behind pointers, so it needs to be neither `Sized` nor `Clone`.
+    #[derive(PureClone)]
+    struct Handle<T: ?Sized> {
+        ptr: Rc<T>,
+        id: u64,
+    }
+
+    #[derive(PureClone)]
+    struct Borrowed<'a, T>
+    where
+        T: ?Sized,
+    {
+        r: &'a T,
+        w: std::rc::Weak<T>,
+        a: Arc<T>,
+        p: *const T,
+    }
+
+    let c: Cell<Handle<dyn Observer>> = Cell::new(Handle {
+        ptr: Rc::new(Logger),
+        id: 1,
+    });
+    assert_eq!(c.get().ptr.id(), 7);
+    assert_eq!(c.get().id, 1);
+
+    let logger = Logger;
+    let rc: Rc<dyn Observer> = Rc::new(Logger);
+    let c: Cell<Borrowed<dyn Observer>> = Cell::new(Borrowed {
+        r: &logger,
+        w: Rc::downgrade(&rc),
+        a: Arc::new(Logger),
+        p: &logger,
+    });
+    let b = c.get();
+    assert_eq!(b.r.id(), 7);
+    assert_eq!(b.w.upgrade().unwrap().id(), 7);
+    assert_eq!(b.a.id(), 7);
+    assert_eq!(unsafe { &*b.p }.id(), 7);
+}
+
+#[test]
+fn pure_clone_impl() {
+    struct Versioned<T>
+    where
+        T: ?Sized,
+    {
+        value: Rc<T>,
+        version: u32,
+    }
+
+    #[pure_clone_impl(unsafe)]
+    impl<T> Clone for Versioned<T>
+    where
+        T: ?Sized,
+    {
+        fn clone(&self) -> Self {
+            Self {
+                value: self.value.clone(),
+                version: self.version + 1,
+            }
+        }
+    }
+
+    let c: Cell<Versioned<str>> = Cell::new(Versioned {
+        value: Rc::from("v"),
+        version: 0,
+    });
+    assert_eq!(c.get().version, 1);
+    assert_eq!(&*c.get().pure_clone().value, "v");
+}
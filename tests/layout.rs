@@ -0,0 +1,101 @@
+#![cfg(feature = "alloc")]
+
+use std::mem::{align_of, align_of_val, size_of, size_of_val};
+use std::rc::Rc;
+
+use clone_cell::cell::Cell;
+
+#[allow(dead_code)]
+struct Large {
+    bytes: [u8; 1000],
+    x: u64,
+    s: Rc<str>,
+}
+
+#[allow(dead_code)]
+#[repr(align(64))]
+struct Aligned(u8);
+
+struct Zst;
+
+/// Asserts at compile time that `Cell<T>` has the same size and alignment as `T`, which the pointer
+/// casts in `Cell::from_mut` and `Cell::as_slice_of_cells` rely on.
+macro_rules! assert_same_layout {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            const _: () = assert!(size_of::<Cell<$t>>() == size_of::<$t>());
+            const _: () = assert!(align_of::<Cell<$t>>() == align_of::<$t>());
+        )+
+    };
+}
+
+assert_same_layout!(u8, u16, u32, u64, u128, usize, i8, i64, f32, f64, bool, char);
+assert_same_layout!(
+    Rc<i32>,
+    Rc<str>,
+    Option<Rc<i32>>,
+    Box<[u8]>,
+    Vec<u8>,
+    String
+);
+assert_same_layout!(
+    Large,
+    Aligned,
+    [Aligned; 3],
+    Zst,
+    (),
+    [u64; 0],
+    (u8, u32, u16)
+);
+
+#[test]
+fn unsized_layout() {
+    let s: &mut [u16] = &mut [1, 2, 3];
+    let (size, align) = (size_of_val(s), align_of_val(s));
+    let c: &Cell<[u16]> = Cell::from_mut(s);
+    assert_eq!(size_of_val(c), size);
+    assert_eq!(align_of_val(c), align);
+
+    let s: &mut str = &mut String::from("hello");
+    let len = s.len();
+    assert_eq!(size_of_val(Cell::from_mut(s)), len);
+}
+
+#[test]
+fn slice_round_trip() {
+    fn check<T>(s: &mut [T]) {
+        let addrs: Vec<*const T> = s.iter().map(|t| t as *const T).collect();
+        let len = s.len();
+        let start = s.as_ptr();
+        let c: &Cell<[T]> = Cell::from_mut(s);
+        assert_eq!(c.as_ptr() as *const T, start);
+        let cells: &[Cell<T>] = c.as_slice_of_cells();
+        assert_eq!(cells.len(), len);
+        assert_eq!(cells.as_ptr() as *const T, start);
+        for (cell, &addr) in cells.iter().zip(&addrs) {
+            assert_eq!(cell.as_ptr() as *const T, addr);
+        }
+    }
+
+    check(&mut [1u8, 2, 3]);
+    check(&mut [u128::MAX; 5]);
+    check(&mut [Rc::new(1), Rc::new(2)]);
+    check(&mut [Zst, Zst, Zst]);
+    check(&mut [Aligned(1), Aligned(2)]);
+    check::<u32>(&mut []);
+    check(&mut vec![String::from("a"); 4]);
+}
+
+#[test]
+fn values_round_trip() {
+    let mut s = [Rc::new(1), Rc::new(2), Rc::new(3)];
+    let cells = Cell::from_mut(&mut s[..]).as_slice_of_cells();
+    cells[0].set(Rc::new(10));
+    cells[2].swap(&cells[1]);
+    assert_eq!(s.map(|r| *r), [10, 3, 2]);
+
+    let mut v = vec![1, 2, 3];
+    let c = Cell::from_mut(&mut v);
+    c.set(vec![4, 5]);
+    assert_eq!(v, [4, 5]);
+}
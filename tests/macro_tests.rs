@@ -0,0 +1,487 @@
+#![cfg(feature = "alloc")]
+
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use clone_cell::{
+    assert_not_pure_clone, assert_pure_clone, cell::Cell, clone::PureClone, pure_clone,
+};
+
+/// Stands in for a library crate that opts its own types into `PureClone`.
+mod foreign {
+    use std::rc::Rc;
+
+    use clone_cell::{clone::PureClone, pure_clone_remote};
+
+    pub struct Id(pub u32);
+
+    impl Clone for Id {
+        fn clone(&self) -> Self {
+            Self(self.0)
+        }
+    }
+
+    #[derive(Clone)]
+    pub struct Wrapper<T>(pub T);
+
+    pub struct Pair<'a, T, const N: usize>
+    where
+        T: ?Sized,
+    {
+        pub shared: Rc<T>,
+        pub borrowed: &'a [u8; N],
+    }
+
+    // `#[derive(Clone)]` would require `T: Clone`.
+    impl<T, const N: usize> Clone for Pair<'_, T, N>
+    where
+        T: ?Sized,
+    {
+        fn clone(&self) -> Self {
+            Self {
+                shared: self.shared.clone(),
+                borrowed: self.borrowed,
+            }
+        }
+    }
+
+    /// A map with bounds both in its generic parameters and in a `where` clause.
+    #[derive(Clone)]
+    pub struct SmallMap<K, V, const N: usize>
+    where
+        K: Ord,
+    {
+        pub entries: [Option<(K, V)>; N],
+    }
+
+    #[derive(Clone)]
+    pub struct Tagged<'a, T>(pub &'a str, pub Vec<T>);
+
+    pure_clone_remote! {
+        unsafe impl PureClone for Id;
+        unsafe impl<T: PureClone> PureClone for Wrapper<T>;
+        unsafe impl<'a, T, const N: usize> PureClone for Pair<'a, T, N> where T: ?Sized;
+        unsafe impl<K: PureClone + Ord, V: PureClone, const N: usize> PureClone for SmallMap<K, V, N>;
+        unsafe impl<'a, T> PureClone for Tagged<'a, T> where T: PureClone + 'a, Vec<T>: Clone;
+    }
+}
+
+#[test]
+fn pure_clone_remote() {
+    use foreign::{Id, Pair, SmallMap, Tagged, Wrapper};
+
+    let c = Cell::new(Wrapper(Id(0)));
+    c.set(Wrapper(Id(42)));
+    assert_eq!(c.get().0 .0, 42);
+
+    let bytes = [1, 2, 3];
+    let shared: Rc<str> = Rc::from("shared");
+    let c = Cell::new(Pair {
+        shared: shared.clone(),
+        borrowed: &bytes,
+    });
+    assert_eq!(&*c.get().shared, "shared");
+    assert_eq!(c.get().borrowed, &[1, 2, 3]);
+    assert_eq!(Rc::strong_count(&shared), 2);
+
+    assert_pure_clone!(SmallMap<Rc<str>, Id, 2>, Tagged<'static, Wrapper<Rc<i32>>>);
+    assert_not_pure_clone!(SmallMap<i32, Cell<i32>, 2>, Tagged<'static, Cell<i32>>);
+
+    let c = Cell::new(SmallMap {
+        entries: [Some((shared.clone(), Id(1))), None],
+    });
+    let map = c.get();
+    assert_eq!(Rc::strong_count(&shared), 4);
+    assert_eq!(map.entries[0].as_ref().map(|(_, v)| v.0), Some(1));
+
+    let c = Cell::new(Tagged("tag", vec![shared.clone()]));
+    assert_eq!(c.get().0, "tag");
+    assert_eq!(c.get().1.len(), 1);
+}
+
+#[test]
+fn pure_clone_struct() {
+    pure_clone! {
+        struct Foo {
+            x: i32,
+            y: f32,
+        }
+    }
+
+    let c = Cell::new(Foo { x: 0, y: 0.0 });
+    c.set(Foo { x: 42, y: -42.0 });
+    assert_eq!(c.get().x, 42);
+    assert_eq!(c.get().y, -42.0);
+}
+
+#[test]
+fn pure_clone_inherent_clone_method() {
+    pure_clone! {
+        struct Foo {
+            data: i32,
+            ptr: Rc<Cell<Option<Bar>>>,
+        }
+
+        struct Bar {
+            f: Foo,
+        }
+    }
+
+    // The generated `clone` method never calls this "inherent" `clone` method.
+    impl Foo {
+        #[allow(dead_code)]
+        fn clone(&self) -> Self {
+            // Clears out the cell we're contained in...
+            self.ptr.set(None);
+            Self {
+                data: self.data,
+                ptr: self.ptr.clone(),
+            }
+        }
+    }
+
+    let c: Rc<Cell<Option<Bar>>> = Rc::new(Cell::new(None));
+    c.set(Some(Bar {
+        f: Foo {
+            data: 42,
+            ptr: c.clone(),
+        },
+    }));
+    assert_eq!(c.get().unwrap().f.data, 42);
+}
+
+#[test]
+fn pure_clone_type_params() {
+    #[derive(Debug, PartialEq)]
+    struct Foo;
+
+    pure_clone! {
+        struct Bar<T> {
+            t: T,
+        }
+
+        struct Baz<T, U> {
+            t: Rc<T>,
+            foo: Rc<Foo>,
+            bar: Bar<U>,
+            foobar: Arc<Bar<U>>,
+        }
+    }
+
+    let baz = Baz {
+        t: Rc::new(Foo),
+        foo: Rc::new(Foo),
+        bar: Bar { t: 42 },
+        foobar: Arc::new(Bar { t: 43 }),
+    };
+    assert_eq!(*baz.pure_clone().t, Foo);
+    assert_eq!(*baz.pure_clone().foo, Foo);
+    assert_eq!(baz.pure_clone().bar.t, 42);
+    assert_eq!(baz.pure_clone().foobar.t, 43);
+}
+
+#[test]
+fn pure_clone_lifetimes_and_where_clauses() {
+    pure_clone! {
+        struct Foo<'a, 'b, T>
+        where
+            T: ?Sized,
+        {
+            a: &'a T,
+            b: &'b str,
+        }
+
+        struct Bar<'a, T>(&'a T, Rc<T>)
+        where
+            T: ?Sized + 'a;
+    }
+
+    let i = 42;
+    let f = Foo { a: &i, b: "b" };
+    assert_eq!(*f.pure_clone().a, i);
+    assert_eq!(f.pure_clone().b, "b");
+
+    let s: Rc<str> = Rc::from("bar");
+    let c = Cell::new(Bar("bar", s.clone()));
+    assert_eq!(c.get().0, "bar");
+    assert_eq!(Rc::strong_count(&s), 2);
+}
+
+#[test]
+fn pure_clone_tuple_and_unit() {
+    pure_clone! {
+        #[derive(Debug, PartialEq)]
+        pub struct Foo(pub u8, Rc<str>);
+
+        #[derive(Debug, PartialEq)]
+        struct Wide(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+        #[derive(Debug, PartialEq)]
+        struct Unit;
+    }
+
+    let c = Cell::new(Foo(1, Rc::from("foo")));
+    assert_eq!(c.get(), Foo(1, Rc::from("foo")));
+    let w = Wide(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+    assert_eq!(w.pure_clone(), w);
+    assert_eq!(Cell::new(Unit).get(), Unit);
+}
+
+#[test]
+fn pure_clone_variant() {
+    pure_clone! {
+        #[derive(Debug, PartialEq)]
+        struct Foo;
+
+        #[derive(Debug, PartialEq)]
+        enum Bar<T> {
+            _X,
+            _Y(i32),
+            Z { x: (usize,), y: Box<T>, z: Foo },
+        }
+
+        #[derive(Debug, PartialEq)]
+        enum Discriminants {
+            A = 1,
+            /// Docs on a variant.
+            B = 4,
+        }
+
+        #[derive(Debug, PartialEq)]
+        enum Never {}
+    }
+
+    let b = Bar::Z {
+        x: (42,),
+        y: Box::new('y'),
+        z: Foo,
+    };
+    let b2 = b.pure_clone();
+    assert_eq!(b, b2);
+    assert_eq!(Bar::<()>::_Y(1).pure_clone(), Bar::_Y(1));
+    assert_eq!(Cell::new(Discriminants::B).get() as u8, 4);
+    assert_eq!(Discriminants::A.pure_clone(), Discriminants::A);
+    let c: Cell<Result<i32, Never>> = Cell::new(Ok(42));
+    assert_eq!(c.get(), Ok(42));
+}
+
+#[test]
+fn pure_clone_typestate() {
+    struct Open;
+
+    pure_clone! {
+        // `Open` is never cloned, so it doesn't need to be `Clone`.
+        struct Handle<S>(u32, PhantomData<S>);
+    }
+
+    let c = Cell::new(Handle::<Open>(42, PhantomData));
+    assert_eq!(c.get().0, 42);
+}
+
+#[test]
+fn pure_clone_hygiene() {
+    #[allow(dead_code, non_camel_case_types)]
+    mod shadowed {
+        use std::rc::Rc;
+
+        use ::clone_cell::pure_clone;
+
+        // These shadow the crates the generated code refers to.
+        pub struct core;
+        mod clone_cell {}
+
+        pure_clone! {
+            pub struct Foo<T> {
+                pub p: Rc<T>,
+                pub x: i32,
+            }
+
+            pub enum Bar<T> {
+                Y(T, T),
+                Z { self_: T, clone: T },
+            }
+        }
+    }
+
+    let c = Cell::new(shadowed::Foo {
+        p: Rc::new(42),
+        x: 21,
+    });
+    assert_eq!(*c.get().p, 42);
+    assert_eq!(c.get().x, 21);
+    let c = Cell::new(shadowed::Bar::Z { self_: 1, clone: 2 });
+    assert!(matches!(c.get(), shadowed::Bar::Z { self_: 1, clone: 2 }));
+    assert!(matches!(
+        shadowed::Bar::Y(1, 2).pure_clone(),
+        shadowed::Bar::Y(1, 2)
+    ));
+}
+
+mod assertions {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use clone_cell::{assert_not_pure_clone, assert_pure_clone, pure_clone};
+
+    pure_clone! {
+        pub struct Foo<'a, T> {
+            pub t: Rc<T>,
+            pub s: &'a str,
+        }
+    }
+
+    #[derive(Clone)]
+    pub struct Bar;
+
+    // Module-level assertions, with and without a trailing comma.
+    assert_pure_clone!(Foo<'static, RefCell<i32>>, Rc<Foo<'static, Bar>>);
+    assert_pure_clone!(Foo<'static, i32>,);
+    assert_not_pure_clone!(Bar, Option<Bar>,);
+}
+
+#[test]
+fn assert_pure_clone() {
+    struct NotClone;
+
+    assert_pure_clone!(assertions::Foo<'static, NotClone>, Rc<str>);
+    assert_not_pure_clone!(NotClone, assertions::Bar);
+}
+
+#[test]
+fn impl_pure_clone_for_copy() {
+    use clone_cell::impl_pure_clone_for_copy;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Id(u32);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Flags {
+        read: bool,
+        write: bool,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Meters(f64);
+
+    impl_pure_clone_for_copy!(unsafe Id, unsafe Flags, unsafe Meters,);
+    assert_pure_clone!(Id, Flags, Meters, Option<Id>, Rc<Flags>);
+
+    let c = Cell::new((Id(1), Meters(0.5)));
+    c.set((Id(2), c.get().1));
+    assert_eq!(c.get(), (Id(2), Meters(0.5)));
+
+    let flags = Cell::new(Flags {
+        read: true,
+        write: false,
+    });
+    assert_eq!(flags.get().pure_clone(), flags.get());
+}
+
+#[test]
+fn pure_clone_trait_object() {
+    use clone_cell::{clone::DynPureClone, pure_clone_trait_object};
+
+    trait Node: DynPureClone {
+        fn value(&self) -> i32;
+    }
+
+    pure_clone_trait_object!(Node);
+
+    #[derive(Clone)]
+    struct Leaf(i32);
+
+    unsafe impl PureClone for Leaf {}
+
+    impl Node for Leaf {
+        fn value(&self) -> i32 {
+            self.0
+        }
+    }
+
+    #[derive(Clone)]
+    struct Shared(Rc<i32>);
+
+    unsafe impl PureClone for Shared {}
+
+    impl Node for Shared {
+        fn value(&self) -> i32 {
+            *self.0
+        }
+    }
+
+    assert_pure_clone!(
+        Box<dyn Node>,
+        Box<dyn Node + Send>,
+        Box<dyn Node + Send + Sync>
+    );
+
+    let p = Rc::new(2);
+    let nodes: Vec<Cell<Box<dyn Node>>> = vec![
+        Cell::new(Box::new(Leaf(1))),
+        Cell::new(Box::new(Shared(p.clone()))),
+    ];
+    let values: Vec<_> = nodes.iter().map(|n| n.get().value()).collect();
+    assert_eq!(values, [1, 2]);
+
+    let b = nodes[1].get();
+    assert_eq!(Rc::strong_count(&p), 3);
+    nodes[1].set(nodes[0].get());
+    assert_eq!(Rc::strong_count(&p), 2);
+    assert_eq!(b.value(), 2);
+    assert_eq!(nodes[1].get().value(), 1);
+
+    let c: Cell<Box<dyn Node + Send + Sync>> = Cell::new(Box::new(Leaf(3)));
+    assert_eq!(c.get().value(), 3);
+}
+
+#[test]
+fn pure_callback() {
+    use clone_cell::pure_callback;
+
+    struct Event {
+        x: i32,
+    }
+
+    struct State {
+        clicks: std::cell::Cell<u32>,
+    }
+
+    pure_callback! {
+        /// Counts clicks to the right of a threshold.
+        struct OnClick(state: Rc<State>, threshold: i32) |&self, event: &Event| -> bool {
+            let hit = event.x > self.threshold;
+            if hit {
+                self.state.clicks.set(self.state.clicks.get() + 1);
+            }
+            hit
+        }
+    }
+
+    pure_callback! {
+        struct Reset(state: Rc<State>,) |&self| {
+            self.state.clicks.set(0);
+        }
+    }
+
+    assert_pure_clone!(OnClick, Reset);
+
+    let state = Rc::new(State {
+        clicks: std::cell::Cell::new(0),
+    });
+    let c = Cell::new(OnClick::new(state.clone(), 10));
+    let a = c.get();
+    let b = c.get();
+    assert_eq!(Rc::strong_count(&state), 4);
+    assert!(a.call(&Event { x: 11 }));
+    assert!(!b.call(&Event { x: 10 }));
+    assert!(b.call(&Event { x: 20 }));
+    assert_eq!(state.clicks.get(), 2);
+
+    c.set(OnClick::new(state.clone(), 0));
+    assert!(c.get().call(&Event { x: 1 }));
+    assert_eq!(state.clicks.get(), 3);
+
+    Reset::new(state.clone()).call();
+    assert_eq!(state.clicks.get(), 0);
+}
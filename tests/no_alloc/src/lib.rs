@@ -0,0 +1,33 @@
+//! Checks that `clone_cell` builds and is usable without the `alloc` crate.
+//!
+//! Build this crate on its own with `cargo build -p clone_cell_no_alloc`. Building it as part of the
+//! whole workspace unifies its `clone_cell` features with those of the other members.
+
+#![no_std]
+
+use clone_cell::{assert_pure_clone, cell::Cell, clone::PureClone, pure_clone};
+
+#[derive(PureClone)]
+pub struct Header {
+    pub tag: [u8; 4],
+    pub len: u32,
+    pub name: &'static str,
+}
+
+pure_clone! {
+    pub struct State(u8, Option<(i32, char)>);
+}
+
+assert_pure_clone!(
+    Header,
+    State,
+    [u8; 4],
+    &'static [Header],
+    (u8, u16),
+    Option<fn()>
+);
+
+pub fn read(header: &Cell<Header>, state: &Cell<State>) -> (u32, u8) {
+    state.set(State(state.get().0 + 1, None));
+    (header.get().len, state.get().0)
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "test-probes")]
+
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use clone_cell::{cell::Cell, clone::check_pure};
+
+struct Foo {
+    data: i32,
+    ptr: Rc<Cell<Option<Foo>>>,
+}
+
+/// Clears out the cell it may be contained in, like the inherent `clone` method in the macro tests.
+impl Clone for Foo {
+    fn clone(&self) -> Self {
+        self.ptr.set(None);
+        Self {
+            data: self.data,
+            ptr: self.ptr.clone(),
+        }
+    }
+}
+
+#[test]
+fn pure() {
+    let p = Rc::new(42);
+    assert!(Rc::ptr_eq(&check_pure(p.clone()), &p));
+    assert_eq!(check_pure(vec![Some(1), None]), [Some(1), None]);
+    assert_eq!(check_pure(Cell::new(42)).get(), 42);
+
+    // Writes outside of `clone` are not counted.
+    let c = Rc::new(Cell::new(0));
+    c.set(1);
+    let d = check_pure(c.clone());
+    d.set(2);
+    assert_eq!(c.get(), 2);
+}
+
+#[test]
+#[should_panic(expected = "`clone` wrote to a `Cell`")]
+fn impure() {
+    let c = Rc::new(Cell::new(None));
+    check_pure(Foo {
+        data: 42,
+        ptr: c.clone(),
+    });
+}
+
+#[test]
+fn impure_in_container() {
+    let c = Rc::new(Cell::new(None));
+    let foo = Foo {
+        data: 42,
+        ptr: c.clone(),
+    };
+    let value = Some((1, vec![foo]));
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| check_pure(value))).is_err());
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "proptest")]
+
+use std::rc::Rc;
+
+use clone_cell::cell::{cell_strategy, Cell};
+use proptest::{
+    prelude::*,
+    test_runner::{TestCaseError, TestError, TestRunner},
+};
+use proptest_derive::Arbitrary;
+
+#[derive(Arbitrary, Debug)]
+struct Observer {
+    id: Cell<u32>,
+    active: Cell<bool>,
+    #[proptest(strategy = "cell_strategy(any::<i64>().prop_map(Rc::new))")]
+    last: Cell<Rc<i64>>,
+}
+
+proptest! {
+    #[test]
+    fn get_set_round_trip(c in any::<Cell<Vec<u8>>>(), v in any::<Vec<u8>>()) {
+        let old = c.get();
+        prop_assert_eq!(c.replace(v.clone()), old);
+        prop_assert_eq!(c.get(), v);
+    }
+
+    #[test]
+    fn derived_struct(o in any::<Observer>()) {
+        o.id.set(o.id.get().wrapping_add(1));
+        o.active.set(!o.active.get());
+        prop_assert_eq!(Rc::strong_count(&o.last.get()), 2);
+    }
+
+    #[test]
+    fn custom_strategy(c in cell_strategy(1..10u8)) {
+        prop_assert!((1..10).contains(&c.get()));
+    }
+}
+
+#[test]
+fn shrinks_inner_value() {
+    let mut runner = TestRunner::deterministic();
+    let result = runner.run(&any::<Cell<Vec<u8>>>(), |_| {
+        Err(TestCaseError::fail("always fails"))
+    });
+    match result {
+        Err(TestError::Fail(_, c)) => assert!(c.get().is_empty()),
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
@@ -0,0 +1,234 @@
+#![cfg(feature = "alloc")]
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+use std::num::{
+    FpCategory, NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize,
+    NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating,
+};
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::rc::{Rc, Weak};
+use std::sync::{Arc, Weak as SyncWeak};
+
+use clone_cell::{assert_not_pure_clone, assert_pure_clone, clone::DynPureClone};
+
+/// A type that is not `Clone`, used where the impl should not require anything of `T`.
+struct NotClone;
+
+#[test]
+fn primitives() {
+    assert_pure_clone!(usize);
+    assert_pure_clone!(u8);
+    assert_pure_clone!(u16);
+    assert_pure_clone!(u32);
+    assert_pure_clone!(u64);
+    assert_pure_clone!(u128);
+    assert_pure_clone!(isize);
+    assert_pure_clone!(i8);
+    assert_pure_clone!(i16);
+    assert_pure_clone!(i32);
+    assert_pure_clone!(i64);
+    assert_pure_clone!(i128);
+    assert_pure_clone!(f32);
+    assert_pure_clone!(f64);
+    assert_pure_clone!(FpCategory);
+    assert_pure_clone!(Ordering);
+    assert_pure_clone!(std::time::Duration);
+    assert_pure_clone!(bool);
+    assert_pure_clone!(char);
+    assert_pure_clone!(());
+}
+
+#[test]
+fn net() {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    assert_pure_clone!(IpAddr, Ipv4Addr, Ipv6Addr);
+    assert_pure_clone!(SocketAddr, SocketAddrV4, SocketAddrV6);
+}
+
+#[test]
+fn non_zero() {
+    assert_pure_clone!(NonZeroUsize);
+    assert_pure_clone!(NonZeroU8);
+    assert_pure_clone!(NonZeroU16);
+    assert_pure_clone!(NonZeroU32);
+    assert_pure_clone!(NonZeroU64);
+    assert_pure_clone!(NonZeroU128);
+    assert_pure_clone!(NonZeroIsize);
+    assert_pure_clone!(NonZeroI8);
+    assert_pure_clone!(NonZeroI16);
+    assert_pure_clone!(NonZeroI32);
+    assert_pure_clone!(NonZeroI64);
+    assert_pure_clone!(NonZeroI128);
+}
+
+#[test]
+fn references_and_markers() {
+    assert_pure_clone!(&NotClone);
+    assert_pure_clone!(&str);
+    assert_pure_clone!(&[NotClone]);
+    assert_pure_clone!(&dyn Fn());
+    assert_pure_clone!(*const NotClone);
+    assert_pure_clone!(*mut str);
+    assert_pure_clone!(std::ptr::NonNull<str>);
+    assert_pure_clone!(PhantomData<NotClone>);
+    assert_pure_clone!(PhantomData<str>);
+}
+
+#[test]
+fn fn_pointers() {
+    assert_pure_clone!(fn());
+    assert_pure_clone!(fn(NotClone) -> NotClone);
+    assert_pure_clone!(unsafe fn(u8, u16));
+    assert_pure_clone!(extern "C" fn(i32) -> i32);
+    assert_pure_clone!(unsafe extern "C" fn());
+    assert_pure_clone!(fn(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8));
+    assert_pure_clone!(Option<fn(&'static str) -> bool>);
+}
+
+#[test]
+fn pointers() {
+    assert_pure_clone!(Rc<NotClone>);
+    assert_pure_clone!(Rc<str>);
+    assert_pure_clone!(Weak<NotClone>);
+    assert_pure_clone!(Weak<[u8]>);
+    assert_pure_clone!(Arc<NotClone>);
+    assert_pure_clone!(Arc<dyn Fn()>);
+    assert_pure_clone!(SyncWeak<NotClone>);
+    assert_pure_clone!(SyncWeak<str>);
+}
+
+#[test]
+fn containers() {
+    assert_pure_clone!(Box<i32>);
+    assert_pure_clone!(Box<[Rc<i32>]>);
+    assert_pure_clone!(Box<str>);
+    assert_pure_clone!(Box<dyn DynPureClone>);
+    assert_pure_clone!(BTreeSet<Rc<i32>>);
+    assert_pure_clone!(Option<Rc<i32>>);
+    assert_pure_clone!(Result<i32, Rc<str>>);
+    assert_pure_clone!(std::ops::ControlFlow<Rc<str>, Vec<u8>>);
+    assert_pure_clone!(String);
+    assert_pure_clone!(std::borrow::Cow<'static, str>);
+    assert_pure_clone!(std::borrow::Cow<'static, [Rc<i32>]>);
+    assert_pure_clone!(std::borrow::Cow<'static, std::ffi::CStr>);
+    assert_pure_clone!(Saturating<u8>);
+    assert_pure_clone!(clone_cell::clone::Pure<NotClone>);
+    assert_pure_clone!(Vec<Rc<i32>>);
+    assert_pure_clone!([Rc<i32>; 0]);
+    assert_pure_clone!([Rc<i32>; 4]);
+}
+
+#[test]
+fn ranges() {
+    type T = Rc<i32>;
+    assert_pure_clone!(Bound<T>);
+    assert_pure_clone!(Range<T>);
+    assert_pure_clone!(RangeFrom<T>);
+    assert_pure_clone!(RangeFull);
+    assert_pure_clone!(RangeInclusive<T>);
+    assert_pure_clone!(RangeTo<T>);
+    assert_pure_clone!(RangeToInclusive<T>);
+}
+
+#[test]
+fn tuples() {
+    type T = Rc<i32>;
+    assert_pure_clone!((T,));
+    assert_pure_clone!((T, T));
+    assert_pure_clone!((T, T, T));
+    assert_pure_clone!((T, T, T, T));
+    assert_pure_clone!((T, T, T, T, T));
+    assert_pure_clone!((T, T, T, T, T, T));
+    assert_pure_clone!((T, T, T, T, T, T, T));
+    assert_pure_clone!((T, T, T, T, T, T, T, T));
+    assert_pure_clone!((T, T, T, T, T, T, T, T, T));
+    assert_pure_clone!((T, T, T, T, T, T, T, T, T, T));
+    assert_pure_clone!((T, T, T, T, T, T, T, T, T, T, T));
+    assert_pure_clone!((T, T, T, T, T, T, T, T, T, T, T, T));
+}
+
+#[test]
+fn is_pure_clone() {
+    use clone_cell::clone::is_pure_clone;
+
+    const STD_TYPES: [bool; 8] = [
+        is_pure_clone::<char>(),
+        is_pure_clone::<&NotClone>(),
+        is_pure_clone::<fn(NotClone)>(),
+        is_pure_clone::<Rc<NotClone>>(),
+        is_pure_clone::<SyncWeak<str>>(),
+        is_pure_clone::<Option<Box<[u8]>>>(),
+        is_pure_clone::<(String, BTreeSet<i32>)>(),
+        is_pure_clone::<[RangeInclusive<u8>; 2]>(),
+    ];
+    assert!(STD_TYPES.into_iter().all(|b| b));
+}
+
+#[test]
+fn pure_cloned() {
+    use clone_cell::clone::{IteratorPureCloneExt, PureClone};
+
+    fn snapshot<T: PureClone>(items: &[T]) -> Vec<T> {
+        items.iter().pure_cloned().collect()
+    }
+
+    let v = [Rc::new(1), Rc::new(2), Rc::new(3)];
+    let s = snapshot(&v);
+    assert!(v.iter().all(|p| Rc::strong_count(p) == 2));
+    assert!(s.iter().zip(&v).all(|(a, b)| Rc::ptr_eq(a, b)));
+    drop(s);
+
+    let mut it = v.iter().pure_cloned();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.next_back().as_deref(), Some(&3));
+    assert_eq!(it.len(), 2);
+    assert_eq!(Rc::strong_count(&v[2]), 1);
+    let first = it.next().unwrap();
+    assert_eq!(Rc::strong_count(&v[0]), 2);
+    assert_eq!(it.len(), 1);
+    let rest: Vec<_> = it.rev().collect();
+    assert_eq!(rest, [Rc::new(2)]);
+    assert_eq!(Rc::strong_count(&v[1]), 2);
+    drop((first, rest));
+    assert!(v.iter().all(|p| Rc::strong_count(p) == 1));
+}
+
+#[test]
+fn not_pure_clone() {
+    use std::cell::RefCell;
+    use std::sync::{Mutex, RwLock};
+
+    use clone_cell::cell::Cell;
+
+    assert_not_pure_clone!(NotClone, &mut i32, RefCell<i32>, Mutex<i32>, RwLock<i32>);
+    assert_not_pure_clone!(Cell<i32>, std::cell::Cell<i32>, Box<dyn Fn()>);
+    assert_not_pure_clone!(Option<RefCell<i32>>, Vec<Cell<i32>>, (i32, RefCell<i32>));
+}
+
+#[test]
+fn trusted() {
+    use clone_cell::clone::TrustedPureClone;
+
+    fn assert_trusted<T: TrustedPureClone>() {}
+
+    type T = Rc<NotClone>;
+    assert_trusted::<u8>();
+    assert_trusted::<NonZeroU32>();
+    assert_trusted::<&NotClone>();
+    assert_trusted::<*const str>();
+    assert_trusted::<std::ptr::NonNull<NotClone>>();
+    assert_trusted::<PhantomData<NotClone>>();
+    assert_trusted::<fn(i32) -> i32>();
+    assert_trusted::<T>();
+    assert_trusted::<Weak<str>>();
+    assert_trusted::<Arc<dyn Fn()>>();
+    assert_trusted::<SyncWeak<NotClone>>();
+    assert_trusted::<Option<T>>();
+    assert_trusted::<Result<T, (u8, char)>>();
+    assert_trusted::<[T; 4]>();
+    assert_trusted::<(T, T, T, T, T, T, T, T, T, T, T, T)>();
+    assert_trusted::<Range<T>>();
+}
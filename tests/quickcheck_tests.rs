@@ -0,0 +1,47 @@
+#![cfg(feature = "quickcheck")]
+
+use clone_cell::cell::Cell;
+use quickcheck::{quickcheck, Arbitrary, Gen, QuickCheck, TestResult};
+
+quickcheck! {
+    fn get_set_round_trip(c: Cell<String>, s: String) -> bool {
+        let old = c.get();
+        c.replace(s.clone()) == old && c.get() == s
+    }
+
+    fn push_str_appends(c: Cell<String>, s: String) -> bool {
+        let expected = c.get() + &s;
+        c.push_str(&s);
+        c.get() == expected
+    }
+}
+
+#[test]
+fn shrink() {
+    let c = Cell::new(String::from("abc"));
+    let cells: Vec<String> = c.shrink().map(Cell::into_inner).collect();
+    let strings: Vec<String> = String::from("abc").shrink().collect();
+    assert!(!cells.is_empty());
+    assert_eq!(cells, strings);
+    assert_eq!(c.get(), "abc");
+
+    let mut g = Gen::new(16);
+    let c = Cell::<String>::arbitrary(&mut g);
+    assert!(c.get().chars().count() <= 16);
+}
+
+#[test]
+fn shrinks_to_minimal_failure() {
+    fn prop(c: Cell<String>) -> TestResult {
+        TestResult::from_bool(!c.get().contains('x'))
+    }
+
+    // `quickcheck` panics with the shrunk input, which should be the single character left.
+    let result = std::panic::catch_unwind(|| {
+        QuickCheck::new()
+            .tests(1000)
+            .quickcheck(prop as fn(Cell<String>) -> TestResult)
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains(r#"Cell { value: "x" }"#), "{message}");
+}
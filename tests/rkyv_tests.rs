@@ -0,0 +1,81 @@
+#![cfg(feature = "rkyv")]
+
+use std::ptr;
+
+use clone_cell::cell::Cell;
+use rkyv::{rancor::Error, Archive, Deserialize, Serialize};
+
+#[derive(Archive, Deserialize, Serialize)]
+struct Node {
+    id: u32,
+    points: Cell<Vec<u32>>,
+    label: Cell<Option<String>>,
+}
+
+#[test]
+fn round_trip() {
+    let node = Node {
+        id: 7,
+        points: Cell::new(vec![1, 2, 3]),
+        label: Cell::new(Some("root".to_owned())),
+    };
+    let bytes = rkyv::to_bytes::<Error>(&node).unwrap();
+
+    let archived = rkyv::access::<ArchivedNode, Error>(&bytes).unwrap();
+    assert_eq!(archived.id, 7);
+    assert_eq!(archived.points.as_slice(), [1, 2, 3]);
+    assert_eq!(archived.label.as_deref(), Some("root"));
+
+    let back = rkyv::deserialize::<Node, Error>(archived).unwrap();
+    assert_eq!(back.id, 7);
+    assert_eq!(back.points.get(), [1, 2, 3]);
+    assert_eq!(back.label.get().as_deref(), Some("root"));
+
+    back.points.set(vec![]);
+    back.label.set(None);
+    let bytes = rkyv::to_bytes::<Error>(&back).unwrap();
+    let back = rkyv::from_bytes::<Node, Error>(&bytes).unwrap();
+    assert!(back.points.get().is_empty());
+    assert_eq!(back.label.get(), None);
+}
+
+#[test]
+fn transparent() {
+    let c = Cell::new(vec![4u32, 5]);
+    let bytes = rkyv::to_bytes::<Error>(&c).unwrap();
+    assert_eq!(bytes[..], rkyv::to_bytes::<Error>(&c.get()).unwrap()[..]);
+}
+
+/// Serializing this clears the `Cell` it points to, which rkyv does after serializing the fields
+/// before it, but before resolving them.
+struct Clobber(*const Cell<Vec<u32>>);
+
+impl Archive for Clobber {
+    type Archived = ();
+    type Resolver = ();
+
+    fn resolve(&self, _: (), _: rkyv::Place<()>) {}
+}
+
+impl<S> Serialize<S> for Clobber
+where
+    S: rkyv::rancor::Fallible + ?Sized,
+{
+    fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+        unsafe { (*self.0).set(vec![9; 100]) };
+        Ok(())
+    }
+}
+
+#[test]
+fn changed_before_resolve() {
+    let mut pair = (Cell::new(vec![1u32, 2, 3]), Clobber(ptr::null()));
+    pair.1 = Clobber(&pair.0);
+    let bytes = rkyv::to_bytes::<Error>(&pair).unwrap();
+    assert_eq!(pair.0.get(), [9; 100]);
+
+    // The archive holds the value that was serialized, not the one in the `Cell` during `resolve`.
+    let archived =
+        rkyv::access::<rkyv::Archived<(Cell<Vec<u32>>, Clobber)>, Error>(&bytes).unwrap();
+    assert_eq!(archived.0.as_slice(), [1, 2, 3]);
+}
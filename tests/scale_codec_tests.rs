@@ -0,0 +1,88 @@
+#![cfg(feature = "scale-codec")]
+
+use clone_cell::cell::Cell;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+#[derive(Decode, Encode)]
+struct Account {
+    nonce: Cell<u32>,
+    free: Cell<u128>,
+    locks: Cell<Vec<(u8, u64)>>,
+    label: Cell<Option<String>>,
+}
+
+/// `Account` without cells, as declared by the runtime.
+#[derive(Debug, Decode, Encode, PartialEq)]
+struct PlainAccount {
+    nonce: u32,
+    free: u128,
+    locks: Vec<(u8, u64)>,
+    label: Option<String>,
+}
+
+#[test]
+fn round_trip() {
+    let account = Account {
+        nonce: Cell::new(3),
+        free: Cell::new(1_000_000),
+        locks: Cell::new(vec![(1, 10), (2, 20)]),
+        label: Cell::new(Some("alice".to_owned())),
+    };
+    let plain = PlainAccount {
+        nonce: 3,
+        free: 1_000_000,
+        locks: vec![(1, 10), (2, 20)],
+        label: Some("alice".to_owned()),
+    };
+    let bytes = account.encode();
+    assert_eq!(bytes, plain.encode());
+    assert_eq!(account.encoded_size(), bytes.len());
+
+    let back = Account::decode(&mut &bytes[..]).unwrap();
+    assert_eq!(back.nonce.get(), 3);
+    assert_eq!(back.free.get(), 1_000_000);
+    assert_eq!(back.locks.get(), [(1, 10), (2, 20)]);
+    assert_eq!(back.label.get().as_deref(), Some("alice"));
+
+    back.nonce.set(4);
+    back.label.set(None);
+    let plain = PlainAccount::decode(&mut &back.encode()[..]).unwrap();
+    assert_eq!(plain.nonce, 4);
+    assert_eq!(plain.label, None);
+}
+
+#[test]
+fn transparent() {
+    let c = Cell::new(vec![Some(1u16), None]);
+    assert_eq!(c.encode(), c.get().encode());
+    // Computing the hint of the content would take another clone.
+    assert_eq!(c.size_hint(), 0);
+    assert_eq!(Cell::<u64>::encoded_fixed_size(), Some(8));
+    assert_eq!(Cell::<Option<u64>>::max_encoded_len(), 9);
+
+    let bytes = 7u32.encode();
+    let mut input = &bytes[..];
+    Cell::<u32>::skip(&mut input).unwrap();
+    assert!(input.is_empty());
+    assert!(Cell::<u32>::decode(&mut &bytes[..2]).is_err());
+}
+
+#[cfg(feature = "scale-info")]
+#[test]
+fn type_info() {
+    use scale_info::{meta_type, TypeDef, TypeInfo};
+
+    #[derive(TypeInfo)]
+    struct Wrapper {
+        _nonce: Cell<u32>,
+    }
+
+    assert_eq!(Cell::<Vec<u8>>::type_info(), Vec::<u8>::type_info());
+    assert_eq!(meta_type::<Cell<u32>>(), meta_type::<u32>());
+    let TypeDef::Composite(composite) = Wrapper::type_info().type_def else {
+        panic!("not a composite");
+    };
+    // Only the type name as written in the source differs.
+    assert_eq!(composite.fields[0].ty, meta_type::<u32>());
+    assert_eq!(composite.fields[0].type_name, Some("Cell<u32>"));
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "schemars")]
+
+use std::rc::Rc;
+
+use clone_cell::cell::Cell;
+use schemars::{schema_for, JsonSchema};
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+struct Limits {
+    max_connections: u32,
+    timeout_ms: Option<u64>,
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+struct Config {
+    name: Cell<Rc<str>>,
+    limits: Cell<Rc<Limits>>,
+    tags: Cell<Vec<String>>,
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[schemars(rename = "Config")]
+struct PlainConfig {
+    name: Rc<str>,
+    limits: Rc<Limits>,
+    tags: Vec<String>,
+}
+
+#[test]
+fn transparent() {
+    assert_eq!(schema_for!(Cell<Limits>), schema_for!(Limits));
+    assert_eq!(
+        schema_for!(Cell<Option<Limits>>),
+        schema_for!(Option<Limits>)
+    );
+    assert_eq!(schema_for!(Cell<Vec<u8>>), schema_for!(Vec<u8>));
+    assert_eq!(<Cell<Limits>>::schema_id(), <Limits>::schema_id(),);
+    assert_eq!(schema_for!(Config), schema_for!(PlainConfig));
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+struct Retry {
+    attempts: Option<u32>,
+    delay_ms: Cell<Option<u32>>,
+}
+
+/// Unlike an `Option<T>` field, a `Cell<Option<T>>` field is listed as required.
+#[test]
+fn optional_fields() {
+    let schema = schema_for!(Retry);
+    assert_eq!(
+        schema.get("required"),
+        Some(&serde_json::json!(["delay_ms"]))
+    );
+}
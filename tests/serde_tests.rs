@@ -0,0 +1,69 @@
+#![cfg(feature = "serde")]
+
+use std::rc::Rc;
+
+use clone_cell::cell::Cell;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+struct Model {
+    count: Cell<i32>,
+    name: Cell<Rc<str>>,
+    data: Cell<Option<Vec<u8>>>,
+}
+
+#[test]
+fn round_trip() {
+    let model = Model {
+        count: Cell::new(42),
+        name: Cell::new(Rc::from("foo")),
+        data: Cell::new(Some(vec![1, 2, 3])),
+    };
+    let json = serde_json::to_string(&model).unwrap();
+    assert_eq!(json, r#"{"count":42,"name":"foo","data":[1,2,3]}"#);
+    let back: Model = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, model);
+
+    back.data.set(None);
+    back.count.set(-1);
+    let json = serde_json::to_string(&back).unwrap();
+    assert_eq!(json, r#"{"count":-1,"name":"foo","data":null}"#);
+    assert_eq!(serde_json::from_str::<Model>(&json).unwrap(), back);
+}
+
+#[test]
+fn transparent() {
+    let c = Cell::new(vec![Rc::new(1), Rc::new(2)]);
+    assert_eq!(
+        serde_json::to_value(&c).unwrap(),
+        serde_json::to_value(c.get()).unwrap()
+    );
+    let c: Cell<u8> = serde_json::from_str("7").unwrap();
+    assert_eq!(c.get(), 7);
+    assert!(serde_json::from_str::<Cell<u8>>("256").is_err());
+}
+
+/// A value whose `Serialize` impl writes to the `Cell` it is serialized from.
+#[test]
+fn serialize_reenters() {
+    #[derive(Clone)]
+    struct Reenter(Rc<Cell<Option<Reenter>>>, Rc<str>);
+
+    unsafe impl clone_cell::clone::PureClone for Reenter {}
+
+    impl Serialize for Reenter {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            // This drops the value in the `Cell`, which must not be what is being serialized.
+            self.0.set(None);
+            self.1.serialize(serializer)
+        }
+    }
+
+    let c = Rc::new(Cell::new(None));
+    c.set(Some(Reenter(c.clone(), Rc::from("bar"))));
+    assert_eq!(serde_json::to_string(&*c).unwrap(), r#""bar""#);
+    assert!(c.get().is_none());
+}
@@ -0,0 +1,8 @@
+use clone_cell::clone::PureClone;
+
+#[derive(PureClone)]
+struct Foo {
+    f: Box<dyn Fn(i32) -> i32>,
+}
+
+fn main() {}
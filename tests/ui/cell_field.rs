@@ -0,0 +1,9 @@
+use clone_cell::{cell::Cell, clone::PureClone};
+
+#[derive(PureClone)]
+struct Foo {
+    std: std::cell::Cell<String>,
+    ours: Cell<i32>,
+}
+
+fn main() {}
@@ -7,4 +7,9 @@ struct Foo;
 #[derive(Clone, PureClone)]
 struct Bar;
 
+// A `Clone` derive in a later attribute is visible to the derive macro.
+#[derive(PureClone)]
+#[derive(Debug, Clone)]
+struct Baz;
+
 fn main() {}
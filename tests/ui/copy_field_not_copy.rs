@@ -0,0 +1,12 @@
+use std::rc::Rc;
+
+use clone_cell::clone::PureClone;
+
+#[derive(PureClone)]
+#[pure_clone(copy)]
+struct Foo {
+    x: i32,
+    p: Rc<i32>,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy)]
+struct Id(u32);
+
+clone_cell::impl_pure_clone_for_copy!(Id);
+
+fn main() {}
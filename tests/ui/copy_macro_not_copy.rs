@@ -0,0 +1,11 @@
+use std::rc::Rc;
+
+#[derive(Clone, Copy)]
+struct Id(u32);
+
+#[derive(Clone)]
+struct Handle(Rc<i32>);
+
+clone_cell::impl_pure_clone_for_copy!(unsafe Id, unsafe Handle);
+
+fn main() {}
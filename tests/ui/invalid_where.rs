@@ -0,0 +1,7 @@
+use clone_cell::clone::PureClone;
+
+#[derive(PureClone)]
+#[pure_clone(where = "T: PureClone, T:: 'static")]
+struct Foo<T>(T);
+
+fn main() {}
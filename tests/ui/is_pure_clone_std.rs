@@ -0,0 +1,13 @@
+use std::cell::{Cell, RefCell};
+use std::sync::{Mutex, RwLock};
+
+use clone_cell::clone::is_pure_clone;
+
+const _: bool = is_pure_clone::<RefCell<i32>>();
+const _: bool = is_pure_clone::<Cell<i32>>();
+const _: bool = is_pure_clone::<Mutex<i32>>();
+const _: bool = is_pure_clone::<RwLock<i32>>();
+const _: bool = is_pure_clone::<Box<dyn Fn()>>();
+const _: bool = is_pure_clone::<&'static mut i32>();
+
+fn main() {}
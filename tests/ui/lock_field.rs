@@ -0,0 +1,11 @@
+use std::sync::{Mutex, RwLock};
+
+use clone_cell::clone::PureClone;
+
+#[derive(PureClone)]
+struct Foo {
+    m: Mutex<i32>,
+    rw: RwLock<i32>,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use clone_cell::clone::pure_clone_impl;
+
+struct Foo;
+
+#[pure_clone_impl]
+impl Clone for Foo {
+    fn clone(&self) -> Self {
+        Self
+    }
+}
+
+struct Bar;
+
+#[pure_clone_impl(unsafe)]
+impl Default for Bar {
+    fn default() -> Self {
+        Self
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use std::cell::RefCell;
+
+use clone_cell::clone::PureClone;
+
+#[derive(PureClone)]
+struct Foo {
+    x: RefCell<i32>,
+}
+
+fn main() {}
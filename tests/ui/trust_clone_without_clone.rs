@@ -0,0 +1,9 @@
+use clone_cell::clone::PureClone;
+
+#[derive(PureClone)]
+#[pure_clone(unsafe_trust_clone)]
+struct Foo {
+    x: i32,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use clone_cell::clone::PureClone;
+
+#[derive(PureClone)]
+#[pure_clone(trusted)]
+struct Foo<T> {
+    t: T,
+    buf: Vec<u8>,
+}
+
+#[derive(PureClone)]
+#[pure_clone(trusted, unsafe_trust_clone)]
+struct Bar {
+    x: i32,
+}
+
+fn assert_trusted<T: clone_cell::clone::TrustedPureClone>() {}
+
+fn main() {
+    assert_trusted::<Foo<Vec<u8>>>();
+}
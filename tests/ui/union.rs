@@ -0,0 +1,9 @@
+use clone_cell::clone::PureClone;
+
+#[derive(PureClone)]
+union Foo {
+    x: u32,
+    y: f32,
+}
+
+fn main() {}
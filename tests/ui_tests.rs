@@ -1,4 +1,4 @@
-#![cfg(feature = "derive")]
+#![cfg(all(feature = "alloc", feature = "derive"))]
 
 #[test]
 fn ui() {
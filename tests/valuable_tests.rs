@@ -0,0 +1,126 @@
+#![cfg(feature = "valuable")]
+
+use clone_cell::cell::Cell;
+use valuable::{NamedValues, Valuable, Value, Visit};
+
+/// Records the shape of everything visited, descending into structs and lists.
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl Visit for Recorder {
+    fn visit_value(&mut self, value: Value<'_>) {
+        match value {
+            Value::Structable(s) => {
+                self.0.push(format!("struct {}", s.definition().name()));
+                s.visit(self);
+                self.0.push("end".to_owned());
+            }
+            Value::Listable(l) => {
+                self.0.push("list".to_owned());
+                l.visit(self);
+                self.0.push("end".to_owned());
+            }
+            value => self.0.push(format!("{value:?}")),
+        }
+    }
+
+    fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+        for (field, value) in named_values {
+            self.0.push(field.name().to_owned());
+            self.visit_value(*value);
+        }
+    }
+}
+
+fn record(value: &dyn Valuable) -> Vec<String> {
+    let mut recorder = Recorder::default();
+    recorder.visit_value(value.as_value());
+    recorder.0
+}
+
+#[test]
+fn same_shape_as_value() {
+    let tags = vec!["a".to_owned(), "b".to_owned()];
+    let c = Cell::new(tags.clone());
+
+    let mut expected = vec!["struct Cell".to_owned(), "value".to_owned()];
+    expected.extend(record(&tags));
+    expected.push("end".to_owned());
+    assert_eq!(record(&c), expected);
+
+    assert_eq!(
+        record(&Cell::new(42u64)),
+        ["struct Cell", "value", "42", "end"]
+    );
+}
+
+#[test]
+fn cell_fields() {
+    #[derive(Debug, Valuable)]
+    struct Account {
+        balance: Cell<u64>,
+        tags: Cell<Vec<String>>,
+    }
+
+    let account = Account {
+        balance: Cell::new(5),
+        tags: Cell::new(vec!["new".to_owned()]),
+    };
+    // The recorded shape matches `Debug`.
+    assert_eq!(
+        format!("{:?}", account.as_value()),
+        format!("{:?}", account),
+    );
+    assert_eq!(
+        record(&account),
+        [
+            "struct Account",
+            "balance",
+            "struct Cell",
+            "value",
+            "5",
+            "end",
+            "tags",
+            "struct Cell",
+            "value",
+            "list",
+            "\"new\"",
+            "end",
+            "end",
+            "end",
+        ],
+    );
+}
+
+#[test]
+fn set_during_visit() {
+    /// Replaces the value of the cell while it is being visited.
+    struct Clobber<'a> {
+        cell: &'a Cell<Vec<String>>,
+        seen: Vec<String>,
+    }
+
+    impl Visit for Clobber<'_> {
+        fn visit_value(&mut self, _: Value<'_>) {}
+
+        fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+            self.cell.set(vec!["clobbered".to_owned()]);
+            let Some(Value::Listable(l)) = named_values.get_by_name("value") else {
+                panic!("expected a list");
+            };
+            let mut recorder = Recorder::default();
+            l.visit(&mut recorder);
+            self.seen = recorder.0;
+        }
+    }
+
+    let c = Cell::new(vec!["original".to_owned()]);
+    let mut visit = Clobber {
+        cell: &c,
+        seen: Vec::new(),
+    };
+    c.visit(&mut visit);
+    // The visitor saw the value from before `set`, which was cloned out of the cell.
+    assert_eq!(visit.seen, ["\"original\""]);
+    assert_eq!(c.get(), ["clobbered"]);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "zerocopy")]
+
+use clone_cell::cell::Cell;
+use zerocopy::{FromBytes, FromZeros, IntoBytes, KnownLayout, Unaligned};
+
+#[derive(FromBytes, IntoBytes, KnownLayout)]
+#[repr(C)]
+struct Header {
+    len: Cell<u32>,
+    flags: Cell<u16>,
+    kind: Cell<u8>,
+    reserved: u8,
+}
+
+/// Shares the header and updates it through the cells.
+fn ack(header: &Header) {
+    header.flags.set(header.flags.get() | 0x8000);
+    header.kind.set(header.kind.get() + 1);
+}
+
+#[test]
+fn mut_from_bytes() {
+    // Back the buffer with `u32`s so that it is aligned for `Header`.
+    let mut words = [0u32; 3];
+    let buf = words.as_mut_bytes();
+    buf[..4].copy_from_slice(&7u32.to_ne_bytes());
+    buf[4..6].copy_from_slice(&1u16.to_ne_bytes());
+    buf[6] = 2;
+
+    let (header, rest) = Header::mut_from_prefix(buf).unwrap();
+    assert_eq!(rest.len(), 4);
+    assert_eq!(header.len.get(), 7);
+    ack(header);
+    ack(header);
+    header.len.set(header.len.get() + 4);
+    assert_eq!(header.as_mut_bytes()[6], 4);
+
+    let buf = words.as_bytes();
+    assert_eq!(buf[..4], 11u32.to_ne_bytes());
+    assert_eq!(buf[4..6], 0x8001u16.to_ne_bytes());
+    assert_eq!(buf[6..], [4, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn slice_of_cells() {
+    let mut words = [0u16; 4];
+    words
+        .as_mut_bytes()
+        .copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let counters = <[Cell<u16>]>::mut_from_bytes(words.as_mut_bytes()).unwrap();
+    let counters = &*counters;
+    assert_eq!(counters.len(), 4);
+    for c in counters {
+        c.set(c.get().swap_bytes());
+    }
+    assert_eq!(words.as_bytes(), [2, 1, 4, 3, 6, 5, 8, 7]);
+}
+
+#[test]
+fn zeroed_and_unaligned() {
+    fn assert_unaligned<T: Unaligned>() {}
+    assert_unaligned::<Cell<u8>>();
+    assert_unaligned::<Cell<[u8; 3]>>();
+
+    let header = Header::new_zeroed();
+    assert_eq!(header.len.get(), 0);
+    let c = Cell::<u64>::new_zeroed();
+    assert_eq!(c.get(), 0);
+}